@@ -0,0 +1,64 @@
+//! Measures decoding throughput across thread counts.
+//!
+//! Usage: `cargo run --release --example decode_bench -- <video.ivf>`
+//!
+//! Scaling depends on the content: tile threading needs a stream encoded
+//! with several tile columns, so use 1080p or above.
+
+use std::{env, fs::File, io::BufReader, time::Instant};
+
+use vp9::{ivf::IvfDemuxer, Frame, Vp9Decoder, Vp9DecoderConfig};
+
+type Configure = fn(Vp9DecoderConfig) -> Vp9DecoderConfig;
+
+fn main() -> anyhow::Result<()> {
+    let path = env::args()
+        .nth(1)
+        .ok_or_else(|| anyhow::anyhow!("usage: decode_bench <video.ivf>"))?;
+    let packets = read_packets(&path)?;
+
+    println!("{:<24} {:>8} {:>10}", "mode", "threads", "fps");
+    for &threads in &[1, 2, 4, 8] {
+        let modes: [(&str, Configure); 3] = [
+            ("tile", |config| config),
+            ("row_mt", |config| config.row_mt(true).loop_filter_opt(true)),
+            ("frame_threading", |config| config.frame_threading(true)),
+        ];
+        for (name, configure) in modes {
+            let decoder = configure(Vp9Decoder::builder().threads(threads)).build()?;
+            let fps = run(decoder, &packets)?;
+            println!("{:<24} {:>8} {:>10.1}", name, threads, fps);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_packets(path: &str) -> anyhow::Result<Vec<Vec<u8>>> {
+    let mut demuxer = IvfDemuxer::new(BufReader::new(File::open(path)?))?;
+    let mut packets = Vec::new();
+    while let Some(frame) = demuxer.next_frame()? {
+        packets.push(frame.data.to_vec());
+    }
+    Ok(packets)
+}
+
+/// Decodes every packet and returns the number of frames per second.
+fn run(mut decoder: Vp9Decoder, packets: &[Vec<u8>]) -> anyhow::Result<f64> {
+    let mut frame = Frame::new(0, 0);
+    let mut frames = 0;
+
+    let start = Instant::now();
+    for (pts, packet) in packets.iter().enumerate() {
+        decoder.decode(packet, pts as u64)?;
+        while decoder.next_frame(&mut frame)? {
+            frames += 1;
+        }
+    }
+    decoder.flush()?;
+    while decoder.next_frame(&mut frame)? {
+        frames += 1;
+    }
+
+    Ok(frames as f64 / start.elapsed().as_secs_f64())
+}
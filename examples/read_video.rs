@@ -23,7 +23,7 @@ use winit::{
 fn main() -> anyhow::Result<()> {
     let file = File::open("assets/video.ivf")?;
     let mut demuxer = IvfDemuxer::new(BufReader::new(file))?;
-    let mut decoder = Vp9Decoder::new();
+    let mut decoder = Vp9Decoder::new()?;
     let mut video_frame = Frame::new(demuxer.header().width, demuxer.header().height);
 
     let event_loop = EventLoop::new();
@@ -240,24 +240,30 @@ fn main() -> anyhow::Result<()> {
 
         let target = surface.get_current_frame().unwrap();
 
-        let data_frame = match demuxer.next_frame().unwrap() {
-            Some(f) => f,
+        match demuxer.next_frame().unwrap() {
+            Some(data_frame) => {
+                let target_time = data_frame.timestamp as f64 * time_base;
+                let current_time = start.elapsed().as_secs_f64();
+                if current_time < target_time {
+                    thread::sleep(Duration::from_secs_f64(target_time - current_time));
+                }
+
+                decoder
+                    .decode(data_frame.data, data_frame.timestamp)
+                    .unwrap();
+
+                while decoder.next_frame(&mut video_frame).unwrap() {}
+            }
             None => {
-                *control_flow = ControlFlow::Exit;
-                return;
+                // End of stream: show the frames libvpx still holds, then exit.
+                decoder.flush().unwrap();
+                if !decoder.next_frame(&mut video_frame).unwrap() {
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
             }
-        };
-
-        let target_time = data_frame.timestamp as f64 * time_base;
-        let current_time = start.elapsed().as_secs_f64();
-        if current_time < target_time {
-            thread::sleep(Duration::from_secs_f64(target_time - current_time));
         }
 
-        decoder.decode(data_frame.data).unwrap();
-
-        while decoder.next_frame(&mut video_frame).unwrap() {}
-
         // Write frame data to the GPU
         queue.write_texture(
             ImageCopyTexture {
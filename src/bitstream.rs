@@ -0,0 +1,265 @@
+//! Minimal parsing of the VP9 uncompressed frame header, for decisions
+//! that have to be made before data is handed to libvpx.
+
+const FRAME_MARKER: u32 = 2;
+const SYNC_CODE: u32 = 0x49_83_42;
+const CS_RGB: u32 = 7;
+
+/// Whether any frame in `data` (a frame or superframe) updates a
+/// reference buffer.
+///
+/// Returns `true` if the data cannot be parsed, as that is the safe answer.
+pub(crate) fn updates_references(data: &[u8]) -> bool {
+    let (sizes, bytes_per_size) = match superframe_index(data) {
+        Some(index) => index,
+        None => return refresh_frame_flags(data) != Some(0),
+    };
+
+    let mut offset = 0;
+    for size in sizes.chunks_exact(bytes_per_size) {
+        let size = size
+            .iter()
+            .rev()
+            .fold(0, |size, &byte| (size << 8) | byte as usize);
+        let frame = match data.get(offset..offset + size) {
+            Some(frame) => frame,
+            None => return true,
+        };
+        if refresh_frame_flags(frame) != Some(0) {
+            return true;
+        }
+        offset += size;
+    }
+    false
+}
+
+/// Returns the frame sizes of the superframe index at the end of `data`
+/// and the number of bytes used for each size (bitstream spec, Annex B).
+fn superframe_index(data: &[u8]) -> Option<(&[u8], usize)> {
+    let marker = *data.last()?;
+    if marker & 0xe0 != 0xc0 {
+        return None;
+    }
+    let frames = (marker & 0x7) as usize + 1;
+    let bytes_per_size = ((marker >> 3) & 0x3) as usize + 1;
+    let index_size = 2 + bytes_per_size * frames;
+
+    let index = data.get(data.len().checked_sub(index_size)?..)?;
+    if index[0] != marker {
+        return None;
+    }
+    Some((&index[1..index.len() - 1], bytes_per_size))
+}
+
+/// Reads `refresh_frame_flags` from an uncompressed frame header.
+fn refresh_frame_flags(frame: &[u8]) -> Option<u8> {
+    let mut reader = BitReader::new(frame);
+    if reader.read(2)? != FRAME_MARKER {
+        return None;
+    }
+    let profile = reader.read(1)? | reader.read(1)? << 1;
+    if profile == 3 {
+        reader.read(1)?;
+    }
+
+    let show_existing_frame = reader.read(1)?;
+    if show_existing_frame == 1 {
+        return Some(0);
+    }
+
+    let frame_type = reader.read(1)?;
+    let show_frame = reader.read(1)?;
+    let error_resilient_mode = reader.read(1)?;
+    if frame_type == 0 {
+        // Keyframes refresh every reference.
+        return Some(0xff);
+    }
+
+    let intra_only = if show_frame == 1 { 0 } else { reader.read(1)? };
+    if error_resilient_mode == 0 {
+        // reset_frame_context
+        reader.read(2)?;
+    }
+    if intra_only == 1 {
+        if reader.read(24)? != SYNC_CODE {
+            return None;
+        }
+        if profile > 0 {
+            skip_color_config(&mut reader, profile)?;
+        }
+    }
+
+    Some(reader.read(8)? as u8)
+}
+
+fn skip_color_config(reader: &mut BitReader, profile: u32) -> Option<()> {
+    if profile >= 2 {
+        // ten_or_twelve_bit
+        reader.read(1)?;
+    }
+    let color_space = reader.read(3)?;
+    if color_space != CS_RGB {
+        // color_range
+        reader.read(1)?;
+        if profile == 1 || profile == 3 {
+            // subsampling_x, subsampling_y, reserved_zero
+            reader.read(3)?;
+        }
+    } else if profile == 1 || profile == 3 {
+        // reserved_zero
+        reader.read(1)?;
+    }
+    Some(())
+}
+
+/// Reads big-endian bit fields.
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn read(&mut self, bits: u32) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.data.get(self.position / 8)?;
+            let bit = (byte >> (7 - self.position % 8)) & 1;
+            value = (value << 1) | bit as u32;
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_streams::{show_existing_frame, superframe, KeyFrame};
+
+    /// Packs a string of `0` and `1` into bytes, ignoring spaces.
+    fn bits(bits: &str) -> Vec<u8> {
+        let bits: Vec<u8> = bits
+            .bytes()
+            .filter(|&b| b != b' ')
+            .map(|b| b - b'0')
+            .collect();
+        bits.chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, &bit)| byte | bit << (7 - i))
+            })
+            .collect()
+    }
+
+    const SYNC: &str = "0100 1001 1000 0011 0100 0010";
+
+    #[test]
+    fn keyframes_refresh_everything() {
+        for frame in [
+            KeyFrame::new(64, 64).encode(),
+            KeyFrame::new(33, 17).hidden().encode(),
+        ] {
+            assert_eq!(refresh_frame_flags(&frame), Some(0xff));
+            assert!(updates_references(&frame));
+        }
+    }
+
+    #[test]
+    fn show_existing_frame_refreshes_nothing() {
+        assert_eq!(refresh_frame_flags(&show_existing_frame(3)), Some(0));
+        assert!(!updates_references(&show_existing_frame(3)));
+    }
+
+    #[test]
+    fn inter_frame_flags() {
+        // marker, profile 0, not show_existing, inter, shown,
+        // error resilient, then refresh_frame_flags
+        let frame = bits("10 00 0 1 1 1 0000 0100");
+        assert_eq!(refresh_frame_flags(&frame), Some(0x04));
+        assert!(updates_references(&frame));
+
+        // Not error resilient, so reset_frame_context follows.
+        let frame = bits("10 00 0 1 1 0 00 0000 0000");
+        assert_eq!(refresh_frame_flags(&frame), Some(0));
+        assert!(!updates_references(&frame));
+    }
+
+    #[test]
+    fn intra_only_frame_flags() {
+        // Profile 0 has no color config.
+        let frame = bits(&format!("10 00 0 1 0 1 1 {SYNC} 1000 0001"));
+        assert_eq!(refresh_frame_flags(&frame), Some(0x81));
+
+        // Profile 1: BT.601, color_range, subsampling and reserved bit.
+        let frame = bits(&format!("10 10 0 1 0 1 1 {SYNC} 001 0 100 0011 0000"));
+        assert_eq!(refresh_frame_flags(&frame), Some(0x30));
+
+        // Profile 1 RGB has only the reserved bit.
+        let frame = bits(&format!("10 10 0 1 0 1 1 {SYNC} 111 0 0000 0010"));
+        assert_eq!(refresh_frame_flags(&frame), Some(0x02));
+
+        // Profile 2: bit depth, color space and range.
+        let frame = bits(&format!("10 01 0 1 0 1 1 {SYNC} 1 010 1 1111 1110"));
+        assert_eq!(refresh_frame_flags(&frame), Some(0xfe));
+
+        // Profile 3 has a reserved bit after the profile and the full
+        // color config.
+        let frame = bits(&format!("10 11 0 0 1 0 1 1 {SYNC} 0 010 0 000 0000 1000"));
+        assert_eq!(refresh_frame_flags(&frame), Some(0x08));
+
+        let frame = bits("10 00 0 1 0 1 1 0000 0000 0000 0000 0000 0000 0000 0000");
+        assert_eq!(refresh_frame_flags(&frame), None, "bad sync code");
+    }
+
+    #[test]
+    fn superframe_index_sizes() {
+        let frames = [
+            KeyFrame::new(64, 64).hidden().encode(),
+            show_existing_frame(0),
+        ];
+        let data = superframe(&frames);
+        let (sizes, bytes_per_size) = superframe_index(&data).unwrap();
+        assert_eq!(bytes_per_size, 4);
+        assert_eq!(sizes.len(), 8);
+        assert_eq!(sizes[..4], (frames[0].len() as u32).to_le_bytes());
+        assert_eq!(sizes[4..], 1u32.to_le_bytes());
+
+        assert!(superframe_index(&frames[0]).is_none());
+        assert!(superframe_index(&[]).is_none());
+        // A marker byte without a matching index start.
+        assert!(superframe_index(&[0, 0, 0xc0]).is_none());
+    }
+
+    #[test]
+    fn superframe_updates_if_any_frame_does() {
+        let hidden = KeyFrame::new(64, 64).hidden().encode();
+        assert!(updates_references(&superframe(&[
+            hidden,
+            show_existing_frame(0)
+        ])));
+        assert!(!updates_references(&superframe(&[
+            show_existing_frame(1),
+            show_existing_frame(2)
+        ])));
+    }
+
+    #[test]
+    fn unparsable_data_updates_references() {
+        assert_eq!(refresh_frame_flags(&[]), None);
+        assert!(updates_references(&[]));
+        assert!(updates_references(&[0xff; 4]));
+        // Truncated inter frame header.
+        assert!(updates_references(&bits("10 00 0 1 1 0 00 00")));
+
+        // An index with sizes past the end of the data.
+        let mut data = superframe(&[show_existing_frame(0), show_existing_frame(1)]);
+        data[3] = 0xff;
+        assert!(updates_references(&data));
+    }
+}
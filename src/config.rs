@@ -0,0 +1,300 @@
+use crate::{
+    ffi::{
+        vp8_postproc_cfg, vp8_postproc_level_VP8_ADDNOISE, vp8_postproc_level_VP8_DEBLOCK,
+        vp8_postproc_level_VP8_DEMACROBLOCK, vp8_postproc_level_VP8_MFQE, vpx_codec_caps_t,
+        vpx_codec_dec_cfg, vpx_codec_flags_t, VPX_CODEC_CAP_ERROR_CONCEALMENT,
+        VPX_CODEC_CAP_INPUT_FRAGMENTS, VPX_CODEC_CAP_POSTPROC, VPX_CODEC_USE_ERROR_CONCEALMENT,
+        VPX_CODEC_USE_FRAME_THREADING, VPX_CODEC_USE_INPUT_FRAGMENTS, VPX_CODEC_USE_POSTPROC,
+    },
+    Error, Vp9Decoder,
+};
+
+/// Options for constructing a [`Vp9Decoder`].
+///
+/// Obtained from [`Vp9Decoder::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct Vp9DecoderConfig {
+    threads: u32,
+    width: u32,
+    height: u32,
+    pub(crate) postproc: Option<PostProcConfig>,
+    error_concealment: bool,
+    pub(crate) input_fragments: bool,
+    frame_threading: bool,
+    pub(crate) row_mt: bool,
+    pub(crate) loop_filter_opt: bool,
+    pub(crate) corruption_policy: CorruptionPolicy,
+}
+
+impl Vp9DecoderConfig {
+    /// Sets the maximum number of threads libvpx may use.
+    ///
+    /// `0` leaves the choice to libvpx.
+    pub fn threads(mut self, threads: u32) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Hints the dimensions of the stream ahead of the first keyframe.
+    pub fn size_hint(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Enables postprocessing (`VPX_CODEC_USE_POSTPROC`) with the
+    /// given initial filters.
+    ///
+    /// Filters can only be changed later with
+    /// [`Vp9Decoder::set_postproc`] if this is set.
+    pub fn postproc(mut self, config: PostProcConfig) -> Self {
+        self.postproc = Some(config);
+        self
+    }
+
+    /// Enables error concealment (`VPX_CODEC_USE_ERROR_CONCEALMENT`).
+    ///
+    /// `build` fails with [`Error::MissingCapability`] if libvpx was
+    /// built without it; upstream libvpx only supports it for VP8.
+    pub fn error_concealment(mut self, enabled: bool) -> Self {
+        self.error_concealment = enabled;
+        self
+    }
+
+    /// Enables partial input (`VPX_CODEC_USE_INPUT_FRAGMENTS`), for
+    /// [`Vp9Decoder::decode_fragment`].
+    ///
+    /// `build` fails with [`Error::MissingCapability`] if the decoder
+    /// lacks `VPX_CODEC_CAP_INPUT_FRAGMENTS`, as upstream VP9 does.
+    pub fn input_fragments(mut self, enabled: bool) -> Self {
+        self.input_fragments = enabled;
+        self
+    }
+
+    /// Enables frame-based multithreading (`VPX_CODEC_USE_FRAME_THREADING`).
+    pub fn frame_threading(mut self, enabled: bool) -> Self {
+        self.frame_threading = enabled;
+        self
+    }
+
+    /// Enables row-based multithreading (`VP9D_SET_ROW_MT`).
+    ///
+    /// Only takes effect with more than one thread.
+    pub fn row_mt(mut self, enabled: bool) -> Self {
+        self.row_mt = enabled;
+        self
+    }
+
+    /// Enables the multithreaded loop filter optimization
+    /// (`VP9D_SET_LOOP_FILTER_OPT`), used together with row multithreading.
+    pub fn loop_filter_opt(mut self, enabled: bool) -> Self {
+        self.loop_filter_opt = enabled;
+        self
+    }
+
+    /// Sets how the decoder reacts to corrupt input.
+    pub fn corruption_policy(mut self, policy: CorruptionPolicy) -> Self {
+        self.corruption_policy = policy;
+        self
+    }
+
+    /// Creates the decoder.
+    pub fn build(self) -> Result<Vp9Decoder, Error> {
+        Vp9Decoder::with_config(&self)
+    }
+
+    pub(crate) fn dec_cfg(&self) -> vpx_codec_dec_cfg {
+        vpx_codec_dec_cfg {
+            threads: self.threads,
+            w: self.width,
+            h: self.height,
+        }
+    }
+
+    /// Checks that the codec supports the requested init flags.
+    pub(crate) fn check_caps(&self, caps: vpx_codec_caps_t) -> Result<(), Error> {
+        let required = [
+            (
+                self.postproc.is_some(),
+                VPX_CODEC_CAP_POSTPROC,
+                "postprocessing",
+            ),
+            (
+                self.error_concealment,
+                VPX_CODEC_CAP_ERROR_CONCEALMENT,
+                "error concealment",
+            ),
+            (
+                self.input_fragments,
+                VPX_CODEC_CAP_INPUT_FRAGMENTS,
+                "input fragments",
+            ),
+        ];
+        for (requested, cap, name) in required {
+            if requested && caps & cap as vpx_codec_caps_t == 0 {
+                return Err(Error::MissingCapability(name));
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn init_flags(&self) -> vpx_codec_flags_t {
+        let mut flags = 0;
+        if self.postproc.is_some() {
+            flags |= VPX_CODEC_USE_POSTPROC;
+        }
+        if self.error_concealment {
+            flags |= VPX_CODEC_USE_ERROR_CONCEALMENT;
+        }
+        if self.input_fragments {
+            flags |= VPX_CODEC_USE_INPUT_FRAGMENTS;
+        }
+        if self.frame_threading {
+            flags |= VPX_CODEC_USE_FRAME_THREADING;
+        }
+        flags as vpx_codec_flags_t
+    }
+}
+
+/// What the decoder does after a [`Error::CorruptFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CorruptionPolicy {
+    /// Keep decoding; later frames may show artifacts.
+    #[default]
+    Ignore,
+    /// Skip input and output until the next keyframe, which
+    /// decodes independently of the damaged references.
+    DropUntilKeyframe,
+}
+
+/// Which frames skip the loop filter, trading quality for speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkipLoopFilter {
+    /// Filter every frame.
+    #[default]
+    None,
+    /// Skip frames that no later frame predicts from, so artifacts
+    /// do not propagate.
+    NonReference,
+    /// Skip every frame. Artifacts accumulate until the next keyframe.
+    All,
+}
+
+/// Postprocessing filters applied to output frames.
+///
+/// Levels range from `0` to `16` and are clamped to that range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PostProcConfig {
+    /// Deblocking filter strength, or `None` to disable it.
+    pub deblock: Option<u8>,
+    /// Additionally removes macroblock edges, at the deblocking strength.
+    pub demacroblock: bool,
+    /// Strength of additive noise to mask banding, or `None` to disable it.
+    pub noise: Option<u8>,
+    /// Enables multi-frame quality enhancement.
+    pub mfqe: bool,
+}
+
+impl PostProcConfig {
+    pub(crate) fn to_ffi(self) -> vp8_postproc_cfg {
+        const MAX_LEVEL: u8 = 16;
+
+        let mut flags = 0;
+        if self.deblock.is_some() {
+            flags |= vp8_postproc_level_VP8_DEBLOCK;
+        }
+        if self.demacroblock {
+            flags |= vp8_postproc_level_VP8_DEMACROBLOCK;
+        }
+        if self.noise.is_some() {
+            flags |= vp8_postproc_level_VP8_ADDNOISE;
+        }
+        if self.mfqe {
+            flags |= vp8_postproc_level_VP8_MFQE;
+        }
+
+        vp8_postproc_cfg {
+            post_proc_flag: flags as i32,
+            deblocking_level: self.deblock.unwrap_or(0).min(MAX_LEVEL).into(),
+            noise_level: self.noise.unwrap_or(0).min(MAX_LEVEL).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postproc_disabled_by_default() {
+        let cfg = PostProcConfig::default().to_ffi();
+        assert_eq!(cfg.post_proc_flag, 0);
+        assert_eq!((cfg.deblocking_level, cfg.noise_level), (0, 0));
+    }
+
+    #[test]
+    fn postproc_flags() {
+        let cases = [
+            (
+                PostProcConfig {
+                    deblock: Some(0),
+                    ..PostProcConfig::default()
+                },
+                vp8_postproc_level_VP8_DEBLOCK,
+            ),
+            (
+                PostProcConfig {
+                    demacroblock: true,
+                    ..PostProcConfig::default()
+                },
+                vp8_postproc_level_VP8_DEMACROBLOCK,
+            ),
+            (
+                PostProcConfig {
+                    noise: Some(3),
+                    ..PostProcConfig::default()
+                },
+                vp8_postproc_level_VP8_ADDNOISE,
+            ),
+            (
+                PostProcConfig {
+                    mfqe: true,
+                    ..PostProcConfig::default()
+                },
+                vp8_postproc_level_VP8_MFQE,
+            ),
+        ];
+        for (config, flag) in cases {
+            assert_eq!(config.to_ffi().post_proc_flag, flag as i32);
+        }
+
+        let all = PostProcConfig {
+            deblock: Some(5),
+            demacroblock: true,
+            noise: Some(7),
+            mfqe: true,
+        }
+        .to_ffi();
+        assert_eq!(all.post_proc_flag, 0b1111);
+        assert_eq!((all.deblocking_level, all.noise_level), (5, 7));
+    }
+
+    #[test]
+    fn postproc_levels_are_clamped() {
+        let cfg = PostProcConfig {
+            deblock: Some(16),
+            noise: Some(255),
+            ..PostProcConfig::default()
+        }
+        .to_ffi();
+        assert_eq!((cfg.deblocking_level, cfg.noise_level), (16, 16));
+
+        // Without `deblock`, demacroblocking runs at level 0.
+        let cfg = PostProcConfig {
+            demacroblock: true,
+            ..PostProcConfig::default()
+        }
+        .to_ffi();
+        assert_eq!(cfg.deblocking_level, 0);
+    }
+}
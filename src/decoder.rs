@@ -1,127 +1,834 @@
-use std::{mem::MaybeUninit, ptr, slice};
+use std::{
+    any::Any,
+    collections::VecDeque,
+    mem::{self, MaybeUninit},
+    os::raw::{c_int, c_uint, c_void},
+    ptr,
+};
 
 use crate::{
+    bitstream,
+    decrypt::{decrypt, DecryptState, Decryptor, ErasedDecryptState},
     ffi::{
-        vpx_codec_ctx, vpx_codec_dec_init_ver, vpx_codec_decode, vpx_codec_destroy,
-        vpx_codec_err_t_VPX_CODEC_OK, vpx_codec_get_frame, vpx_codec_iter_t, vpx_codec_vp9_dx,
-        vpx_img_fmt_VPX_IMG_FMT_I420, VPX_DECODER_ABI_VERSION,
+        vp8_com_control_id_VP8_COPY_REFERENCE, vp8_com_control_id_VP8_SET_POSTPROC,
+        vp8_com_control_id_VP8_SET_REFERENCE, vp8_com_control_id_VP9_GET_REFERENCE,
+        vp8_dec_control_id_VP8D_GET_FRAME_CORRUPTED, vp8_dec_control_id_VP8D_GET_LAST_REF_UPDATES,
+        vp8_dec_control_id_VP8D_GET_LAST_REF_USED, vp8_dec_control_id_VP9D_GET_BIT_DEPTH,
+        vp8_dec_control_id_VP9D_GET_DISPLAY_SIZE, vp8_dec_control_id_VP9D_GET_FRAME_SIZE,
+        vp8_dec_control_id_VP9D_SET_LOOP_FILTER_OPT, vp8_dec_control_id_VP9D_SET_ROW_MT,
+        vp8_dec_control_id_VP9_DECODE_SVC_SPATIAL_LAYER,
+        vp8_dec_control_id_VP9_SET_SKIP_LOOP_FILTER, vp8_dec_control_id_VPXD_GET_LAST_QUANTIZER,
+        vp8_dec_control_id_VPXD_SET_DECRYPTOR, vp9_ref_frame, vpx_codec_control_, vpx_codec_ctx,
+        vpx_codec_dec_init_ver, vpx_codec_decode, vpx_codec_destroy, vpx_codec_err_t,
+        vpx_codec_err_t_VPX_CODEC_CORRUPT_FRAME, vpx_codec_err_t_VPX_CODEC_OK, vpx_codec_get_caps,
+        vpx_codec_get_frame, vpx_codec_get_stream_info, vpx_codec_iter_t,
+        vpx_codec_set_frame_buffer_functions, vpx_codec_vp9_dx, vpx_decrypt_init, vpx_image,
+        vpx_ref_frame, vpx_ref_frame_type, vpx_ref_frame_type_VP8_ALTR_FRAME,
+        vpx_ref_frame_type_VP8_GOLD_FRAME, vpx_ref_frame_type_VP8_LAST_FRAME,
+        VPX_DECODER_ABI_VERSION, VPX_IMG_FMT_HIGHBITDEPTH,
     },
-    Error, Frame,
+    frame::{FrameMeta, Sample},
+    frame_buffer::{get_frame_buffer, release_frame_buffer},
+    peek_stream_info, ChromaSampling, CorruptionPolicy, Error, ErrorDetails, Frame,
+    FrameBufferPool, FrameRef, FrameStats, PlaneRef, PostProcConfig, SkipLoopFilter, StreamInfo,
+    StreamStats, Vp9DecoderConfig,
 };
 
+/// A VP9 decoder backed by a libvpx context.
+///
+/// The decoder is `Send` but not `Sync`: it can be moved to another
+/// thread, e.g. with [`DecoderWorker`](crate::DecoderWorker), but not
+/// used from two threads at once.
 pub struct Vp9Decoder {
     ctx: vpx_codec_ctx,
     iter: vpx_codec_iter_t,
+    /// Dropped after `ctx` is destroyed, which releases its buffers.
+    frame_buffer_pool: Option<Box<dyn Any + Send>>,
+    corruption_policy: CorruptionPolicy,
+    /// Set after a corrupt frame under `CorruptionPolicy::DropUntilKeyframe`.
+    awaiting_keyframe: bool,
+    /// Recent `decode` calls, keyed by the id passed as `user_priv`.
+    packets: VecDeque<PendingPacket>,
+    next_packet_id: usize,
+    skip_loop_filter: SkipLoopFilter,
+    /// Whether libvpx is currently set to skip the loop filter.
+    loop_filter_skipped: bool,
+    keyframes_only: bool,
+    stream_stats: StreamStats,
+    decryptor: Option<Box<dyn ErasedDecryptState>>,
+    input_fragments: bool,
+    /// Whether the decoder was created with `VPX_CODEC_USE_POSTPROC`.
+    postproc: bool,
+    /// Fragments of the current frame, which libvpx keeps pointers to.
+    fragments: Vec<Box<[u8]>>,
+}
+
+/// Number of input packets remembered for frames libvpx has yet to output.
+const MAX_PENDING_PACKETS: usize = 32;
+
+/// What output frames report about the packet they were decoded from.
+#[derive(Debug, Clone, Copy)]
+struct PendingPacket {
+    id: usize,
+    pts: u64,
+    stats: FrameStats,
+}
+
+/// A named reference of the frame being decoded, for
+/// [`Vp9Decoder::set_reference`] and [`Vp9Decoder::copy_reference`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefFrame {
+    Last,
+    Golden,
+    AltRef,
+}
+
+impl RefFrame {
+    fn to_ffi(self) -> vpx_ref_frame_type {
+        match self {
+            RefFrame::Last => vpx_ref_frame_type_VP8_LAST_FRAME,
+            RefFrame::Golden => vpx_ref_frame_type_VP8_GOLD_FRAME,
+            RefFrame::AltRef => vpx_ref_frame_type_VP8_ALTR_FRAME,
+        }
+    }
+
+    /// The buffer slot libvpx resolves the reference to
+    /// (`vp9_set_reference_dec`).
+    fn slot(self) -> c_int {
+        match self {
+            RefFrame::Last => 0,
+            RefFrame::Golden => 1,
+            RefFrame::AltRef => 2,
+        }
+    }
 }
 
 impl Vp9Decoder {
-    pub fn new() -> Self {
+    /// Creates a decoder with the default configuration.
+    pub fn new() -> Result<Self, Error> {
+        Self::builder().build()
+    }
+
+    /// Returns a [`Vp9DecoderConfig`] to customize the decoder.
+    pub fn builder() -> Vp9DecoderConfig {
+        Vp9DecoderConfig::default()
+    }
+
+    pub(crate) fn with_config(config: &Vp9DecoderConfig) -> Result<Self, Error> {
+        config.check_caps(unsafe { vpx_codec_get_caps(vpx_codec_vp9_dx()) })?;
+
         let mut ctx = MaybeUninit::uninit();
-        let cfg = MaybeUninit::zeroed();
+        let cfg = config.dec_cfg();
 
+        // On failure libvpx tears down the context itself.
         let ret = unsafe {
             vpx_codec_dec_init_ver(
                 ctx.as_mut_ptr(),
                 vpx_codec_vp9_dx(),
-                cfg.as_ptr(),
-                0,
+                &cfg,
+                config.init_flags(),
                 VPX_DECODER_ABI_VERSION as i32,
             )
         };
 
-        assert_eq!(
-            ret, vpx_codec_err_t_VPX_CODEC_OK,
-            "failed to initialize decoder"
-        );
+        if ret != vpx_codec_err_t_VPX_CODEC_OK {
+            return Err(Error::from_code(ret));
+        }
 
-        Self {
+        let mut decoder = Self {
             ctx: unsafe { ctx.assume_init() },
             iter: ptr::null_mut(),
+            frame_buffer_pool: None,
+            corruption_policy: config.corruption_policy,
+            awaiting_keyframe: false,
+            packets: VecDeque::with_capacity(MAX_PENDING_PACKETS),
+            next_packet_id: 1,
+            skip_loop_filter: SkipLoopFilter::None,
+            loop_filter_skipped: false,
+            keyframes_only: false,
+            stream_stats: StreamStats::default(),
+            decryptor: None,
+            input_fragments: config.input_fragments,
+            postproc: config.postproc.is_some(),
+            fragments: Vec::new(),
+        };
+        // libvpx reads these when it sets up decoding on the first
+        // `decode`, so they cannot be changed afterwards.
+        if config.row_mt {
+            decoder.control_int(vp8_dec_control_id_VP9D_SET_ROW_MT, 1)?;
+        }
+        if config.loop_filter_opt {
+            decoder.control_int(vp8_dec_control_id_VP9D_SET_LOOP_FILTER_OPT, 1)?;
         }
+        if let Some(postproc) = config.postproc {
+            decoder.set_postproc(postproc)?;
+        }
+        Ok(decoder)
+    }
+
+    /// Sets which frames skip the loop filter (`VP9_SET_SKIP_LOOP_FILTER`),
+    /// for fast previews. Takes effect from the next `decode` call.
+    pub fn set_skip_loop_filter(&mut self, mode: SkipLoopFilter) -> Result<(), Error> {
+        self.skip_loop_filter = mode;
+        // `NonReference` is decided per frame in `decode`.
+        self.set_loop_filter_skipped(mode == SkipLoopFilter::All)
+    }
+
+    /// Drops everything but keyframes before they reach libvpx, for
+    /// scrubbing through a stream.
+    ///
+    /// Turning it off keeps dropping frames up to the next keyframe, as
+    /// they may predict from frames that were dropped.
+    pub fn set_keyframes_only(&mut self, enabled: bool) {
+        if self.keyframes_only && !enabled {
+            self.awaiting_keyframe = true;
+        }
+        self.keyframes_only = enabled;
+    }
+
+    /// Decodes spatial SVC superframes only up to layer `layer`
+    /// (`VP9_DECODE_SVC_SPATIAL_LAYER`), where `0` is the lowest resolution.
+    ///
+    /// Higher layers in each superframe are skipped, so output frames
+    /// have the resolution of `layer`, or of the top layer if there are
+    /// fewer.
+    pub fn set_max_spatial_layer(&mut self, layer: u32) -> Result<(), Error> {
+        self.control_int(
+            vp8_dec_control_id_VP9_DECODE_SVC_SPATIAL_LAYER,
+            layer.min(c_int::MAX as u32) as c_int,
+        )
+    }
+
+    /// The decoded dimensions of the last frame (`VP9D_GET_FRAME_SIZE`).
+    ///
+    /// Fails until a frame has been decoded.
+    pub fn frame_size(&mut self) -> Result<(u32, u32), Error> {
+        self.get_size(vp8_dec_control_id_VP9D_GET_FRAME_SIZE)
+    }
+
+    /// The intended display dimensions of the last frame
+    /// (`VP9D_GET_DISPLAY_SIZE`), which may differ from [`frame_size`](Self::frame_size)
+    /// for anamorphic content. Decoded frames carry their own display size,
+    /// see [`Frame::display_size`].
+    pub fn display_size(&mut self) -> Result<(u32, u32), Error> {
+        self.get_size(vp8_dec_control_id_VP9D_GET_DISPLAY_SIZE)
     }
 
-    pub fn decode(&mut self, data: &[u8]) -> Result<(), Error> {
+    /// The bit depth of the stream (`VP9D_GET_BIT_DEPTH`).
+    pub fn bit_depth(&mut self) -> Result<u32, Error> {
+        let mut bit_depth: c_uint = 0;
+        self.control_ptr(vp8_dec_control_id_VP9D_GET_BIT_DEPTH, &mut bit_depth)?;
+        Ok(bit_depth)
+    }
+
+    /// Makes libvpx decrypt packets through `decryptor` as it reads them
+    /// (`VPXD_SET_DECRYPTOR`), replacing any previous decryptor.
+    ///
+    /// Keyframe detection for [`set_keyframes_only`](Self::set_keyframes_only),
+    /// [`CorruptionPolicy::DropUntilKeyframe`] and
+    /// [`SkipLoopFilter::NonReference`] inspects packets before decryption,
+    /// so those modes do not work on encrypted streams.
+    pub fn set_decryptor<D: Decryptor>(&mut self, decryptor: D) -> Result<(), Error> {
+        let mut state = Box::new(DecryptState::new(decryptor));
+        let mut init = vpx_decrypt_init {
+            decrypt_cb: Some(decrypt::<D>),
+            decrypt_state: &mut *state as *mut DecryptState<D> as *mut c_void,
+        };
+        self.control_ptr(vp8_dec_control_id_VPXD_SET_DECRYPTOR, &mut init)?;
+        self.decryptor = Some(state);
+        Ok(())
+    }
+
+    /// Returns the installed decryptor, e.g. to set the IV of the next packet.
+    ///
+    /// Returns `None` if there is no decryptor or it is not a `D`.
+    pub fn decryptor_mut<D: Decryptor>(&mut self) -> Option<&mut D> {
+        self.decryptor
+            .as_mut()
+            .and_then(|state| state.decryptor_mut().downcast_mut())
+    }
+
+    /// Changes the postprocessing filters, taking effect from the next
+    /// output frame (`VP8_SET_POSTPROC`).
+    ///
+    /// Fails with [`Error::Incapable`] unless postprocessing was enabled
+    /// with [`Vp9DecoderConfig::postproc`].
+    pub fn set_postproc(&mut self, config: PostProcConfig) -> Result<(), Error> {
+        // libvpx accepts the filters regardless, but never applies them.
+        if !self.postproc {
+            return Err(Error::Incapable(ErrorDetails {
+                message: Some("postprocessing was not enabled".to_owned()),
+                detail: None,
+            }));
+        }
+        let mut cfg = config.to_ffi();
+        self.control_ptr(vp8_com_control_id_VP8_SET_POSTPROC, &mut cfg)
+    }
+
+    /// Makes libvpx decode into buffers from `pool` instead of its
+    /// internal allocator. Must be called before the first `decode`.
+    ///
+    /// Frames can then be kept past the next `decode` call with
+    /// [`FrameRef::to_shared`].
+    pub fn set_frame_buffer_pool<P: FrameBufferPool>(&mut self, pool: P) -> Result<(), Error> {
+        if self.frame_buffer_pool.is_some() {
+            // libvpx still holds buffers from the old pool.
+            return Err(Error::InvalidParam(ErrorDetails {
+                message: Some("a frame buffer pool is already installed".to_owned()),
+                detail: None,
+            }));
+        }
+
+        let mut pool = Box::new(pool);
+        let ret = unsafe {
+            vpx_codec_set_frame_buffer_functions(
+                &mut self.ctx,
+                Some(get_frame_buffer::<P>),
+                Some(release_frame_buffer::<P>),
+                &mut *pool as *mut P as *mut c_void,
+            )
+        };
+
+        self.check(ret)?;
+        self.frame_buffer_pool = Some(pool);
+        Ok(())
+    }
+
+    /// Decodes a compressed frame or superframe.
+    ///
+    /// `pts` is reported by the frames it produces, which may be fewer or
+    /// more than one with hidden and repeated frames.
+    pub fn decode(&mut self, data: &[u8], pts: u64) -> Result<(), Error> {
+        self.iter = ptr::null_mut();
+        if self.awaiting_keyframe || self.keyframes_only {
+            if !is_keyframe(data) {
+                return Ok(());
+            }
+            self.awaiting_keyframe = false;
+        }
+
+        if self.skip_loop_filter == SkipLoopFilter::NonReference {
+            self.set_loop_filter_skipped(!bitstream::updates_references(data))?;
+        }
+
+        let id = self.push_packet(pts);
+        if let Some(decryptor) = &mut self.decryptor {
+            decryptor.set_packet(data);
+        }
+
         let ret = unsafe {
             vpx_codec_decode(
                 &mut self.ctx,
                 data.as_ptr(),
                 data.len().try_into().unwrap(),
+                id as *mut c_void,
+                0,
+            )
+        };
+
+        if ret == vpx_codec_err_t_VPX_CODEC_CORRUPT_FRAME
+            && self.corruption_policy == CorruptionPolicy::DropUntilKeyframe
+        {
+            self.awaiting_keyframe = true;
+        }
+        self.check(ret)?;
+        self.record_stats();
+        Ok(())
+    }
+
+    /// Passes the next piece of a frame to the decoder, which must have
+    /// been built with [`Vp9DecoderConfig::input_fragments`]. Call
+    /// [`end_frame`](Self::end_frame) once the frame is complete.
+    ///
+    /// The fragment is copied, as libvpx reads it only when the frame
+    /// ends. Keyframe filtering, per-frame loop filter skipping and
+    /// decryption apply to whole packets only, not to fragments.
+    pub fn decode_fragment(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.check_input_fragments()?;
+        if data.is_empty() {
+            // An empty fragment would end the frame.
+            return Ok(());
+        }
+
+        let fragment: Box<[u8]> = data.into();
+        let ret = unsafe {
+            vpx_codec_decode(
+                &mut self.ctx,
+                fragment.as_ptr(),
+                fragment.len().try_into().unwrap(),
                 ptr::null_mut(),
                 0,
             )
         };
+        self.fragments.push(fragment);
+        self.check(ret)
+    }
 
+    /// Decodes the frame assembled from the fragments passed since the
+    /// last call. Output frames report `pts`.
+    pub fn end_frame(&mut self, pts: u64) -> Result<(), Error> {
+        self.check_input_fragments()?;
         self.iter = ptr::null_mut();
+        let id = self.push_packet(pts);
+        let ret = unsafe { vpx_codec_decode(&mut self.ctx, ptr::null(), 0, id as *mut c_void, 0) };
+        self.fragments.clear();
+        self.check(ret)?;
+        self.record_stats();
+        Ok(())
+    }
 
-        if ret != vpx_codec_err_t_VPX_CODEC_OK {
-            Err(Error(ret))
-        } else {
-            Ok(())
+    /// Returns a copy of reference buffer slot `idx` (`0..8`) as of the
+    /// last decoded frame (`VP9_GET_REFERENCE`).
+    ///
+    /// Use [`copy_reference_frame`](Self::copy_reference_frame) for
+    /// high bit depth streams or to reuse a `Frame`.
+    pub fn reference_frame(&mut self, idx: usize) -> Result<Frame, Error> {
+        let mut frame = Frame::new(0, 0);
+        self.copy_reference_frame(idx, &mut frame)?;
+        Ok(frame)
+    }
+
+    /// Copies reference buffer slot `idx` (`0..8`) into `frame`.
+    pub fn copy_reference_frame<T: Sample>(
+        &mut self,
+        idx: usize,
+        frame: &mut Frame<T>,
+    ) -> Result<(), Error> {
+        let mut reference = vp9_ref_frame {
+            idx: idx.try_into().unwrap_or(c_int::MAX),
+            img: unsafe { mem::zeroed() },
+        };
+        self.control_ptr(vp8_com_control_id_VP9_GET_REFERENCE, &mut reference)?;
+
+        // The image points into libvpx's buffer, valid until the next decode.
+        let meta = FrameMeta {
+            display_size: render_size(&reference.img),
+            ..FrameMeta::default()
+        };
+        let src = FrameRef::new(&reference.img, meta, false)?;
+        copy_frame(&src, frame)
+    }
+
+    /// Returns a copy of one of the references the next frame will
+    /// predict from (`VP8_COPY_REFERENCE`).
+    ///
+    /// Use [`copy_reference`](Self::copy_reference) for high bit depth
+    /// streams or to reuse a `Frame`.
+    pub fn reference(&mut self, reference: RefFrame) -> Result<Frame, Error> {
+        let mut frame = Frame::new(0, 0);
+        self.copy_reference(reference, &mut frame)?;
+        Ok(frame)
+    }
+
+    /// Copies one of the references the next frame will predict from into
+    /// `frame` (`VP8_COPY_REFERENCE`).
+    ///
+    /// libvpx's VP9 decoder implements this for [`RefFrame::Last`] only and
+    /// fails for the others; read their buffer slots with
+    /// [`copy_reference_frame`](Self::copy_reference_frame) instead.
+    pub fn copy_reference<T: Sample>(
+        &mut self,
+        reference: RefFrame,
+        frame: &mut Frame<T>,
+    ) -> Result<(), Error> {
+        let mut current = vp9_ref_frame {
+            idx: reference.slot(),
+            img: unsafe { mem::zeroed() },
+        };
+        self.control_ptr(vp8_com_control_id_VP9_GET_REFERENCE, &mut current)?;
+        let current = current.img;
+        let sampling = ChromaSampling::from_img_fmt(current.fmt)
+            .ok_or(Error::UnsupportedImageFormat(current.fmt))?;
+        // libvpx writes samples of the reference's size into the planes.
+        if (current.fmt & VPX_IMG_FMT_HIGHBITDEPTH != 0) != T::HIGH_BITDEPTH {
+            return Err(Error::SampleTypeMismatch(current.bit_depth));
         }
+
+        // libvpx copies the reference's full 8x8-aligned area, so the
+        // planes are padded to it and cropped afterwards.
+        let mut padded =
+            Frame::<T>::with_sampling(align8(current.d_w), align8(current.d_h), sampling);
+        padded.bit_depth = current.bit_depth;
+        let mut img = frame_image(&padded);
+        img.planes[0] = padded.y_plane.as_mut_ptr() as *mut u8;
+        img.planes[1] = padded.u_plane.as_mut_ptr() as *mut u8;
+        img.planes[2] = padded.v_plane.as_mut_ptr() as *mut u8;
+
+        let mut copy = vpx_ref_frame {
+            frame_type: reference.to_ffi(),
+            img,
+        };
+        self.control_ptr(vp8_com_control_id_VP8_COPY_REFERENCE, &mut copy)?;
+
+        copy.img.d_w = current.d_w;
+        copy.img.d_h = current.d_h;
+        let meta = FrameMeta {
+            display_size: render_size(&current),
+            ..FrameMeta::default()
+        };
+        let src = FrameRef::new(&copy.img, meta, false)?;
+        copy_frame(&src, frame)
+    }
+
+    /// Overwrites one of the current frame's references with `frame`
+    /// (`VP8_SET_REFERENCE`). `frame` must match the stream's current
+    /// size, sampling and bit depth.
+    pub fn set_reference<T: Sample>(
+        &mut self,
+        reference: RefFrame,
+        frame: &Frame<T>,
+    ) -> Result<(), Error> {
+        // libvpx copies the reference's full 8x8-aligned area, so `frame`
+        // is padded to it like the planes `copy_reference` reads into.
+        let padded = pad_to_align8(frame);
+        let mut img = frame_image(&padded);
+        // libvpx only reads from the planes.
+        img.planes[0] = padded.y_plane.as_ptr() as *mut u8;
+        img.planes[1] = padded.u_plane.as_ptr() as *mut u8;
+        img.planes[2] = padded.v_plane.as_ptr() as *mut u8;
+
+        let mut reference = vpx_ref_frame {
+            frame_type: reference.to_ffi(),
+            img,
+        };
+        self.control_ptr(vp8_com_control_id_VP8_SET_REFERENCE, &mut reference)
+    }
+
+    /// The stream properties libvpx recorded when it initialized from
+    /// the first keyframe (`vpx_codec_get_stream_info`).
+    ///
+    /// Use [`frame_size`](Self::frame_size) for the current dimensions.
+    pub fn stream_info(&mut self) -> Result<StreamInfo, Error> {
+        let mut info = StreamInfo::empty_ffi();
+        let ret = unsafe { vpx_codec_get_stream_info(&mut self.ctx, &mut info) };
+        self.check(ret)?;
+        Ok(StreamInfo::from_ffi(&info))
+    }
+
+    /// Signals the end of the stream by decoding a null packet, so frames
+    /// libvpx still holds can be drained with `next_frame`.
+    ///
+    /// Decoding can resume afterwards, e.g. after a seek.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.iter = ptr::null_mut();
+        let ret = unsafe { vpx_codec_decode(&mut self.ctx, ptr::null(), 0, ptr::null_mut(), 0) };
+        self.check(ret)
     }
 
-    pub fn next_frame(&mut self, frame: &mut Frame) -> Result<bool, Error> {
+    /// Statistics over every packet decoded so far.
+    pub fn stream_stats(&self) -> &StreamStats {
+        &self.stream_stats
+    }
+
+    /// Copies the next decoded frame into `frame`. Returns `false`
+    /// once all frames from the last `decode` call have been retrieved.
+    ///
+    /// `frame` is resized if the stream's resolution has changed.
+    /// 10- and 12-bit streams must be decoded into a `Frame<u16>`.
+    pub fn next_frame<T: Sample>(&mut self, frame: &mut Frame<T>) -> Result<bool, Error> {
+        let img = match self.next_frame_ref()? {
+            Some(img) => img,
+            None => return Ok(false),
+        };
+
+        copy_frame(&img, frame)?;
+        Ok(true)
+    }
+
+    /// Borrows the next decoded frame from libvpx without copying it.
+    /// Returns `None` once all frames from the last `decode` call have
+    /// been retrieved.
+    pub fn next_frame_ref(&mut self) -> Result<Option<FrameRef<'_>>, Error> {
+        if self.awaiting_keyframe {
+            return Ok(None);
+        }
+
         let img = unsafe { vpx_codec_get_frame(&mut self.ctx, &mut self.iter) };
         if img.is_null() {
-            return Ok(false);
+            return Ok(None);
+        }
+
+        // The iterator has moved past the frame, so failing here would lose
+        // it; a frame libvpx cannot report on is taken as intact.
+        let mut corrupted: c_int = 0;
+        if self
+            .control_ptr(vp8_dec_control_id_VP8D_GET_FRAME_CORRUPTED, &mut corrupted)
+            .is_err()
+        {
+            corrupted = 0;
         }
+        let display_size = render_size(unsafe { &*img });
 
-        let img = unsafe { &mut *img };
+        let id = unsafe { (*img).user_priv } as usize;
+        let (pts, stats) = self
+            .packets
+            .iter()
+            .rev()
+            .find(|packet| packet.id == id)
+            .map_or((0, FrameStats::default()), |packet| {
+                (packet.pts, packet.stats)
+            });
 
-        if img.fmt != vpx_img_fmt_VPX_IMG_FMT_I420 {
-            return Err(Error(600));
+        let meta = FrameMeta {
+            pts,
+            corrupted: corrupted != 0,
+            display_size,
+            stats,
+        };
+        FrameRef::new(unsafe { &*img }, meta, self.frame_buffer_pool.is_some()).map(Some)
+    }
+}
+
+impl Vp9Decoder {
+    /// Calls `vpx_codec_control_` with an integer argument.
+    fn control_int(&mut self, id: u32, value: c_int) -> Result<(), Error> {
+        let ret = unsafe { vpx_codec_control_(&mut self.ctx, id as c_int, value) };
+        self.check(ret)
+    }
+
+    /// Calls `vpx_codec_control_` with a pointer argument.
+    fn control_ptr<T>(&mut self, id: u32, value: *mut T) -> Result<(), Error> {
+        let ret = unsafe { vpx_codec_control_(&mut self.ctx, id as c_int, value) };
+        self.check(ret)
+    }
+
+    /// Records the timestamp of a `decode` call, returning the id to
+    /// pass as `user_priv`.
+    ///
+    /// Ids are never 0, which would be the null `user_priv` that
+    /// fragments are passed with.
+    fn push_packet(&mut self, pts: u64) -> usize {
+        let id = self.next_packet_id;
+        self.next_packet_id = id.checked_add(1).unwrap_or(1);
+        if self.packets.len() == MAX_PENDING_PACKETS {
+            self.packets.pop_front();
+        }
+        self.packets.push_back(PendingPacket {
+            id,
+            pts,
+            stats: FrameStats::default(),
+        });
+        id
+    }
+
+    /// Queries the statistics of the packet just decoded and attaches them
+    /// to it. Statistics libvpx fails to report are left out rather than
+    /// failing a decode that succeeded.
+    fn record_stats(&mut self) {
+        let mut quantizer: c_int = 0;
+        let mut ref_updates: c_int = 0;
+        let reported = self
+            .control_ptr(vp8_dec_control_id_VPXD_GET_LAST_QUANTIZER, &mut quantizer)
+            .and_then(|()| {
+                self.control_ptr(
+                    vp8_dec_control_id_VP8D_GET_LAST_REF_UPDATES,
+                    &mut ref_updates,
+                )
+            });
+        if reported.is_err() {
+            return;
         }
+        // Not implemented by the VP9 decoder in upstream libvpx.
+        let mut refs_used: c_int = 0;
+        let refs_used = self
+            .control_ptr(vp8_dec_control_id_VP8D_GET_LAST_REF_USED, &mut refs_used)
+            .ok()
+            .map(|()| refs_used as u8);
 
-        assert_eq!(
-            frame.width(),
-            img.d_w,
-            "frame width does not match codec width ({} != {})",
-            frame.width(),
-            img.d_w
-        );
-        assert_eq!(
-            frame.height(),
-            img.d_h,
-            "frame height does not match codec height ({} != {})",
-            frame.height(),
-            img.d_h
-        );
+        let stats = FrameStats {
+            quantizer: quantizer as u32,
+            ref_updates: ref_updates as u8,
+            refs_used,
+        };
+        if let Some(packet) = self.packets.back_mut() {
+            packet.stats = stats;
+        }
+        self.stream_stats.record(&stats);
+    }
 
-        // Copy data into the Frame.
-        unsafe {
-            for y in 0..frame.height() {
-                let y = y as usize;
-                let width = frame.width() as usize;
-                let height = frame.height() as usize;
-
-                let y_plane =
-                    slice::from_raw_parts(img.planes[0].add(y * img.stride[0] as usize), width);
-
-                let range = (y * width)..((y + 1) * width);
-                (&mut frame.y_plane[range]).copy_from_slice(y_plane);
-
-                let range = (y * width / 2)..((y + 1) * (width / 2));
-
-                if y < height / 2 {
-                    let u_plane = slice::from_raw_parts(
-                        img.planes[1].add(y * img.stride[1] as usize),
-                        width / 2,
-                    );
-                    (&mut frame.u_plane[range.clone()]).copy_from_slice(u_plane);
-
-                    let v_plane = slice::from_raw_parts(
-                        img.planes[2].add(y * img.stride[2] as usize),
-                        width / 2,
-                    );
-                    (&mut frame.v_plane[range]).copy_from_slice(v_plane);
-                }
-            }
+    /// Fails unless the decoder was built for input fragments; libvpx
+    /// would take the null packet that ends a frame as a flush otherwise.
+    fn check_input_fragments(&self) -> Result<(), Error> {
+        if self.input_fragments {
+            return Ok(());
         }
+        Err(Error::InvalidParam(ErrorDetails {
+            message: Some("input fragments were not enabled".to_owned()),
+            detail: None,
+        }))
+    }
 
-        Ok(true)
+    fn set_loop_filter_skipped(&mut self, skipped: bool) -> Result<(), Error> {
+        if skipped != self.loop_filter_skipped {
+            self.control_int(
+                vp8_dec_control_id_VP9_SET_SKIP_LOOP_FILTER,
+                skipped as c_int,
+            )?;
+            self.loop_filter_skipped = skipped;
+        }
+        Ok(())
+    }
+
+    /// Queries a control that writes a width and height as `int[2]`.
+    fn get_size(&mut self, id: u32) -> Result<(u32, u32), Error> {
+        let mut size: [c_int; 2] = [0; 2];
+        self.control_ptr(id, size.as_mut_ptr())?;
+        Ok((size[0] as u32, size[1] as u32))
+    }
+
+    /// Converts a libvpx return code, attaching the context's error messages.
+    fn check(&mut self, ret: vpx_codec_err_t) -> Result<(), Error> {
+        if ret != vpx_codec_err_t_VPX_CODEC_OK {
+            Err(Error::from_ctx(ret, &mut self.ctx))
+        } else {
+            Ok(())
+        }
     }
 }
 
+/// Whether `data` starts with a keyframe.
+fn is_keyframe(data: &[u8]) -> bool {
+    peek_stream_info(data).is_ok_and(|info| info.is_keyframe)
+}
+
+/// The display size signalled with `img`.
+///
+/// Taken from the image rather than `VP9D_GET_DISPLAY_SIZE`, which only
+/// reports the most recently decoded frame. Images that carry no render size,
+/// such as postprocessed copies, fall back to the decoded size.
+fn render_size(img: &vpx_image) -> (u32, u32) {
+    if img.r_w == 0 || img.r_h == 0 {
+        (img.d_w, img.d_h)
+    } else {
+        (img.r_w, img.r_h)
+    }
+}
+
+/// Describes the layout of `frame` as a `vpx_image`, leaving the plane
+/// pointers for the caller to set.
+fn frame_image<T: Sample>(frame: &Frame<T>) -> vpx_image {
+    let sample_size = mem::size_of::<T>();
+    let mut img: vpx_image = unsafe { mem::zeroed() };
+    img.fmt = frame.sampling().to_img_fmt(T::HIGH_BITDEPTH);
+    img.bit_depth = frame.bit_depth();
+    img.w = frame.width();
+    img.h = frame.height();
+    img.d_w = frame.width();
+    img.d_h = frame.height();
+    img.x_chroma_shift = frame.sampling().x_shift();
+    img.y_chroma_shift = frame.sampling().y_shift();
+    img.stride[0] = (frame.width() as usize * sample_size) as c_int;
+    img.stride[1] = (frame.uv_width() as usize * sample_size) as c_int;
+    img.stride[2] = img.stride[1];
+    img
+}
+
+/// Rounds `size` up to whole 8x8 blocks, as libvpx allocates frames.
+fn align8(size: u32) -> u32 {
+    (size + 7) & !7
+}
+
+/// Copies `frame` into planes rounded up to whole 8x8 blocks, repeating
+/// the last column and row into the padding.
+fn pad_to_align8<T: Sample>(frame: &Frame<T>) -> Frame<T> {
+    let mut padded = Frame::<T>::with_sampling(
+        align8(frame.width()),
+        align8(frame.height()),
+        frame.sampling(),
+    );
+    padded.bit_depth = frame.bit_depth();
+    let (width, uv_width) = (frame.width() as usize, frame.uv_width() as usize);
+    let (padded_width, padded_uv_width) = (padded.width() as usize, padded.uv_width() as usize);
+    pad_plane(&frame.y_plane, width, &mut padded.y_plane, padded_width);
+    pad_plane(
+        &frame.u_plane,
+        uv_width,
+        &mut padded.u_plane,
+        padded_uv_width,
+    );
+    pad_plane(
+        &frame.v_plane,
+        uv_width,
+        &mut padded.v_plane,
+        padded_uv_width,
+    );
+    padded
+}
+
+fn pad_plane<T: Sample>(src: &[T], width: usize, dst: &mut [T], padded_width: usize) {
+    if src.is_empty() {
+        return;
+    }
+    let height = src.len() / width;
+    for (y, dst_row) in dst.chunks_exact_mut(padded_width).enumerate() {
+        let row = &src[y.min(height - 1) * width..][..width];
+        dst_row[..width].copy_from_slice(row);
+        dst_row[width..].fill(row[width - 1]);
+    }
+}
+
+/// Copies `src` into `frame`, resizing `frame` if needed.
+fn copy_frame<T: Sample>(src: &FrameRef, frame: &mut Frame<T>) -> Result<(), Error> {
+    if src.is_high_bitdepth() != T::HIGH_BITDEPTH {
+        return Err(Error::SampleTypeMismatch(src.bit_depth()));
+    }
+    frame.bit_depth = src.bit_depth();
+    frame.meta = src.meta();
+
+    // VP9 may change resolution at any keyframe, and with reference
+    // scaling even at inter frames.
+    if frame.sampling() != src.sampling() {
+        frame.set_sampling(src.sampling());
+    }
+    if frame.width() != src.width() || frame.height() != src.height() {
+        frame.resize(src.width(), src.height());
+    }
+
+    copy_plane(&mut frame.y_plane, src.y_plane());
+    copy_plane(&mut frame.u_plane, src.u_plane());
+    copy_plane(&mut frame.v_plane, src.v_plane());
+    Ok(())
+}
+
+/// Copies `plane` into `dst`, removing the stride padding.
+fn copy_plane<T: Sample>(dst: &mut [T], plane: PlaneRef) {
+    let width = plane.width() as usize;
+    if width == 0 {
+        return;
+    }
+    for (row, src) in dst.chunks_exact_mut(width).zip(plane.rows()) {
+        assert_eq!(src.len(), mem::size_of_val(row));
+        // `T` is `u8` or `u16`, stored by libvpx in native byte order.
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), row.as_mut_ptr() as *mut u8, src.len());
+        }
+    }
+}
+
+// SAFETY: libvpx contexts have no thread affinity; a context may be used
+// from any thread as long as calls are not concurrent, which `&mut self`
+// guarantees. Internal worker threads are owned by the context. The
+// frame buffer pool and decryptor invoked from libvpx callbacks are `Send`,
+// and `iter` and the pointers in `ctx` only refer to memory owned by the
+// context itself.
+unsafe impl Send for Vp9Decoder {}
+
+// Compile-time checks of the thread-safety guarantees above.
+const _: () = {
+    const fn assert_send<T: Send>() {}
+    const fn assert_sync<T: Sync>() {}
+
+    assert_send::<Vp9Decoder>();
+    assert_send::<Frame>();
+    assert_send::<Frame<u16>>();
+    assert_sync::<Frame>();
+    assert_send::<crate::SharedFrame>();
+    assert_sync::<crate::SharedFrame>();
+    assert_send::<Error>();
+    assert_send::<crate::DecoderWorker>();
+    assert_send::<crate::DecoderWorker<u16>>();
+};
+
 impl Drop for Vp9Decoder {
     fn drop(&mut self) {
         unsafe {
@@ -129,3 +836,29 @@ impl Drop for Vp9Decoder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding_repeats_the_edges() {
+        let mut frame = Frame::new(9, 3);
+        for (i, y) in frame.y_plane.iter_mut().enumerate() {
+            *y = i as u8;
+        }
+        frame.u_plane.fill(1);
+        frame.v_plane.fill(2);
+
+        let padded = pad_to_align8(&frame);
+        assert_eq!((padded.width(), padded.height()), (16, 8));
+        assert_eq!((padded.uv_width(), padded.uv_height()), (8, 4));
+        for (y, row) in padded.y_plane().chunks_exact(16).enumerate() {
+            let src = &frame.y_plane()[y.min(2) * 9..][..9];
+            assert_eq!(&row[..9], src);
+            assert!(row[9..].iter().all(|&sample| sample == src[8]));
+        }
+        assert!(padded.u_plane().iter().all(|&u| u == 1));
+        assert!(padded.v_plane().iter().all(|&v| v == 2));
+    }
+}
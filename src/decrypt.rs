@@ -0,0 +1,164 @@
+use std::{
+    any::Any,
+    os::raw::{c_int, c_uchar, c_void},
+    slice,
+};
+
+/// Decrypts packet data as libvpx reads it, for transport-encrypted streams.
+///
+/// Installed with [`Vp9Decoder::set_decryptor`](crate::Vp9Decoder::set_decryptor).
+pub trait Decryptor: Send + 'static {
+    /// Decrypts `input` into `output`, which have the same length.
+    ///
+    /// `offset` is the position of `input` within the data passed to
+    /// the current `decode` call. libvpx reads, and so decrypts, parts
+    /// of the packet out of order and more than once. Data from anywhere
+    /// else, such as input fragments, is passed through unchanged.
+    fn decrypt(&mut self, offset: usize, input: &[u8], output: &mut [u8]);
+}
+
+/// The `decrypt_state` libvpx passes back to [`decrypt`].
+pub(crate) struct DecryptState<D> {
+    /// Address and length of the packet being decoded.
+    packet: (usize, usize),
+    decryptor: D,
+}
+
+impl<D: Decryptor> DecryptState<D> {
+    pub(crate) fn new(decryptor: D) -> Self {
+        Self {
+            packet: (0, 0),
+            decryptor,
+        }
+    }
+}
+
+/// Type-erased access to a `DecryptState`.
+pub(crate) trait ErasedDecryptState: Send {
+    /// Records the packet about to be decoded, to compute offsets.
+    fn set_packet(&mut self, data: &[u8]);
+
+    fn decryptor_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<D: Decryptor> ErasedDecryptState for DecryptState<D> {
+    fn set_packet(&mut self, data: &[u8]) {
+        self.packet = (data.as_ptr() as usize, data.len());
+    }
+
+    fn decryptor_mut(&mut self) -> &mut dyn Any {
+        &mut self.decryptor
+    }
+}
+
+/// `vpx_decrypt_cb` for a `DecryptState<D>`.
+pub(crate) unsafe extern "C" fn decrypt<D: Decryptor>(
+    state: *mut c_void,
+    input: *const c_uchar,
+    output: *mut c_uchar,
+    count: c_int,
+) {
+    let state = &mut *(state as *mut DecryptState<D>);
+    let count = count as usize;
+    let input = slice::from_raw_parts(input, count);
+    let output = slice::from_raw_parts_mut(output, count);
+
+    let (start, len) = state.packet;
+    match (input.as_ptr() as usize).checked_sub(start) {
+        Some(offset) if offset + count <= len => state.decryptor.decrypt(offset, input, output),
+        // Not part of the packet set by `decode`, e.g. an input fragment.
+        // Panicking here would abort, as it cannot unwind into libvpx.
+        _ => output.copy_from_slice(input),
+    }
+}
+
+/// A run of clear bytes followed by a run of encrypted bytes.
+#[cfg(feature = "aes-ctr")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subsample {
+    pub clear_bytes: usize,
+    pub encrypted_bytes: usize,
+}
+
+/// AES-128 in counter mode with subsample encryption, as used by
+/// WebM encryption.
+///
+/// Set the counter block and subsamples of each packet with
+/// [`set_packet`](Self::set_packet) before decoding it, through
+/// [`Vp9Decoder::decryptor_mut`](crate::Vp9Decoder::decryptor_mut).
+#[cfg(feature = "aes-ctr")]
+pub struct Aes128CtrDecryptor {
+    key: [u8; 16],
+    iv: [u8; 16],
+    subsamples: Vec<Subsample>,
+}
+
+#[cfg(feature = "aes-ctr")]
+impl Aes128CtrDecryptor {
+    pub fn new(key: [u8; 16]) -> Self {
+        Self {
+            key,
+            iv: [0; 16],
+            subsamples: Vec::new(),
+        }
+    }
+
+    /// Sets the parameters of the next packet.
+    ///
+    /// `iv` is the initial counter block; for WebM it is the 8-byte IV
+    /// followed by eight zero bytes. The encrypted bytes of all
+    /// `subsamples` form one keystream. Without subsamples the whole
+    /// packet is encrypted.
+    pub fn set_packet(&mut self, iv: [u8; 16], subsamples: &[Subsample]) {
+        self.iv = iv;
+        self.subsamples.clear();
+        self.subsamples.extend_from_slice(subsamples);
+    }
+
+    /// XORs `input` with the keystream starting at byte `position`.
+    fn apply_keystream(&self, position: usize, input: &[u8], output: &mut [u8]) {
+        use aes::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+
+        let mut cipher = ctr::Ctr128BE::<aes::Aes128>::new(&self.key.into(), &self.iv.into());
+        cipher.seek(position as u64);
+        cipher
+            .apply_keystream_b2b(input, output)
+            .expect("input and output have the same length");
+    }
+}
+
+#[cfg(feature = "aes-ctr")]
+impl Decryptor for Aes128CtrDecryptor {
+    fn decrypt(&mut self, offset: usize, input: &[u8], output: &mut [u8]) {
+        if self.subsamples.is_empty() {
+            self.apply_keystream(offset, input, output);
+            return;
+        }
+
+        // Bytes outside the encrypted runs are clear.
+        output.copy_from_slice(input);
+
+        let end = offset + input.len();
+        let (mut packet_pos, mut stream_pos) = (0, 0);
+        for subsample in &self.subsamples {
+            let start = packet_pos + subsample.clear_bytes;
+            let stop = start + subsample.encrypted_bytes;
+
+            let (from, to) = (start.max(offset), stop.min(end));
+            if from < to {
+                let range = from - offset..to - offset;
+                self.apply_keystream(
+                    stream_pos + (from - start),
+                    &input[range.clone()],
+                    &mut output[range],
+                );
+            }
+
+            stream_pos += subsample.encrypted_bytes;
+            packet_pos = stop;
+            if packet_pos >= end {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    ffi::CStr,
+    fmt::{self, Display},
+    os::raw::c_char,
+};
+
+use crate::ffi::{
+    vpx_codec_ctx, vpx_codec_err_t, vpx_codec_err_t_VPX_CODEC_ABI_MISMATCH,
+    vpx_codec_err_t_VPX_CODEC_CORRUPT_FRAME, vpx_codec_err_t_VPX_CODEC_ERROR,
+    vpx_codec_err_t_VPX_CODEC_INCAPABLE, vpx_codec_err_t_VPX_CODEC_INVALID_PARAM,
+    vpx_codec_err_t_VPX_CODEC_LIST_END, vpx_codec_err_t_VPX_CODEC_MEM_ERROR,
+    vpx_codec_err_t_VPX_CODEC_UNSUP_BITSTREAM, vpx_codec_err_t_VPX_CODEC_UNSUP_FEATURE,
+    vpx_codec_err_to_string, vpx_codec_error, vpx_codec_error_detail, vpx_img_fmt,
+};
+
+/// An error returned by the decoder.
+///
+/// Codec errors carry the messages libvpx reported alongside them.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unspecified codec error{0}")]
+    Unspecified(ErrorDetails),
+    #[error("memory allocation failed{0}")]
+    MemError(ErrorDetails),
+    #[error("ABI version mismatch{0}")]
+    AbiMismatch(ErrorDetails),
+    #[error("codec does not implement the requested capability{0}")]
+    Incapable(ErrorDetails),
+    #[error("bitstream is not supported by the decoder{0}")]
+    UnsupBitstream(ErrorDetails),
+    #[error("bitstream uses an unsupported feature{0}")]
+    UnsupFeature(ErrorDetails),
+    #[error("corrupt frame{0}")]
+    CorruptFrame(ErrorDetails),
+    #[error("invalid parameter{0}")]
+    InvalidParam(ErrorDetails),
+    #[error("end of iteration{0}")]
+    ListEnd(ErrorDetails),
+    #[error("unknown codec error {0}{1}")]
+    Unknown(vpx_codec_err_t, ErrorDetails),
+    #[error("unsupported image format {0:#x}")]
+    UnsupportedImageFormat(vpx_img_fmt),
+    /// The decoded image's bit depth needs a different `Frame` sample type,
+    /// e.g. a 10-bit image decoded into a `Frame<u8>`.
+    #[error("{0}-bit image does not match the frame's sample type")]
+    SampleTypeMismatch(u32),
+    /// The VP9 decoder in the linked libvpx lacks a capability the
+    /// configuration asked for.
+    #[error("decoder does not support {0}")]
+    MissingCapability(&'static str),
+    /// The thread of a [`DecoderWorker`](crate::DecoderWorker) has exited,
+    /// which only happens if it panicked.
+    #[error("decoder worker thread has stopped")]
+    WorkerStopped,
+}
+
+impl Error {
+    #[allow(non_upper_case_globals)]
+    fn new(code: vpx_codec_err_t, details: ErrorDetails) -> Self {
+        match code {
+            vpx_codec_err_t_VPX_CODEC_ERROR => Error::Unspecified(details),
+            vpx_codec_err_t_VPX_CODEC_MEM_ERROR => Error::MemError(details),
+            vpx_codec_err_t_VPX_CODEC_ABI_MISMATCH => Error::AbiMismatch(details),
+            vpx_codec_err_t_VPX_CODEC_INCAPABLE => Error::Incapable(details),
+            vpx_codec_err_t_VPX_CODEC_UNSUP_BITSTREAM => Error::UnsupBitstream(details),
+            vpx_codec_err_t_VPX_CODEC_UNSUP_FEATURE => Error::UnsupFeature(details),
+            vpx_codec_err_t_VPX_CODEC_CORRUPT_FRAME => Error::CorruptFrame(details),
+            vpx_codec_err_t_VPX_CODEC_INVALID_PARAM => Error::InvalidParam(details),
+            vpx_codec_err_t_VPX_CODEC_LIST_END => Error::ListEnd(details),
+            code => Error::Unknown(code, details),
+        }
+    }
+
+    /// Creates an error from a code returned by a call on `ctx`.
+    pub(crate) fn from_ctx(code: vpx_codec_err_t, ctx: &mut vpx_codec_ctx) -> Self {
+        let details = unsafe {
+            ErrorDetails {
+                message: string_from_ptr(vpx_codec_error(ctx)),
+                detail: string_from_ptr(vpx_codec_error_detail(ctx)),
+            }
+        };
+        Self::new(code, details)
+    }
+
+    /// Creates an error from a code without an initialized context to query.
+    pub(crate) fn from_code(code: vpx_codec_err_t) -> Self {
+        let details = ErrorDetails {
+            message: unsafe { string_from_ptr(vpx_codec_err_to_string(code)) },
+            detail: None,
+        };
+        Self::new(code, details)
+    }
+}
+
+/// The messages from `vpx_codec_error` and `vpx_codec_error_detail`.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorDetails {
+    pub message: Option<String>,
+    pub detail: Option<String>,
+}
+
+impl Display for ErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        if let Some(detail) = &self.detail {
+            write!(f, " ({})", detail)?;
+        }
+        Ok(())
+    }
+}
+
+unsafe fn string_from_ptr(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+    }
+}
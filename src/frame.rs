@@ -0,0 +1,384 @@
+use std::fmt::Debug;
+
+use crate::{
+    ffi::{
+        vpx_img_fmt, vpx_img_fmt_VPX_IMG_FMT_I420, vpx_img_fmt_VPX_IMG_FMT_I422,
+        vpx_img_fmt_VPX_IMG_FMT_I440, vpx_img_fmt_VPX_IMG_FMT_I444, VPX_IMG_FMT_HIGHBITDEPTH,
+    },
+    FrameStats,
+};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+}
+
+/// The storage type of a pixel sample: `u8` for 8-bit streams
+/// (profiles 0 and 1), `u16` for 10- and 12-bit streams (profiles 2 and 3).
+pub trait Sample: Copy + Default + Debug + Send + Sync + 'static + sealed::Sealed {
+    /// Whether libvpx outputs these samples in a `VPX_IMG_FMT_HIGHBITDEPTH` image.
+    const HIGH_BITDEPTH: bool;
+}
+
+impl Sample for u8 {
+    const HIGH_BITDEPTH: bool = false;
+}
+
+impl Sample for u16 {
+    const HIGH_BITDEPTH: bool = true;
+}
+
+/// Layout of the chroma (U and V) planes relative to the luma plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChromaSampling {
+    /// Half width, half height.
+    #[default]
+    Cs420,
+    /// Half width, full height.
+    Cs422,
+    /// Full width, half height.
+    Cs440,
+    /// Full width, full height.
+    Cs444,
+}
+
+impl ChromaSampling {
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn from_img_fmt(fmt: vpx_img_fmt) -> Option<Self> {
+        match fmt & !VPX_IMG_FMT_HIGHBITDEPTH {
+            vpx_img_fmt_VPX_IMG_FMT_I420 => Some(ChromaSampling::Cs420),
+            vpx_img_fmt_VPX_IMG_FMT_I422 => Some(ChromaSampling::Cs422),
+            vpx_img_fmt_VPX_IMG_FMT_I440 => Some(ChromaSampling::Cs440),
+            vpx_img_fmt_VPX_IMG_FMT_I444 => Some(ChromaSampling::Cs444),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_img_fmt(self, high_bitdepth: bool) -> vpx_img_fmt {
+        let fmt = match self {
+            ChromaSampling::Cs420 => vpx_img_fmt_VPX_IMG_FMT_I420,
+            ChromaSampling::Cs422 => vpx_img_fmt_VPX_IMG_FMT_I422,
+            ChromaSampling::Cs440 => vpx_img_fmt_VPX_IMG_FMT_I440,
+            ChromaSampling::Cs444 => vpx_img_fmt_VPX_IMG_FMT_I444,
+        };
+        if high_bitdepth {
+            fmt | VPX_IMG_FMT_HIGHBITDEPTH
+        } else {
+            fmt
+        }
+    }
+
+    /// Horizontal subsampling as a power of two.
+    pub fn x_shift(self) -> u32 {
+        match self {
+            ChromaSampling::Cs420 | ChromaSampling::Cs422 => 1,
+            ChromaSampling::Cs440 | ChromaSampling::Cs444 => 0,
+        }
+    }
+
+    /// Vertical subsampling as a power of two.
+    pub fn y_shift(self) -> u32 {
+        match self {
+            ChromaSampling::Cs420 | ChromaSampling::Cs440 => 1,
+            ChromaSampling::Cs422 | ChromaSampling::Cs444 => 0,
+        }
+    }
+}
+
+/// Per-frame information reported by the decoder alongside the image.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct FrameMeta {
+    pub pts: u64,
+    pub corrupted: bool,
+    pub display_size: (u32, u32),
+    pub stats: FrameStats,
+}
+
+/// A frame of planar YUV pixel data.
+///
+/// Samples are `u8` by default; use `Frame<u16>` to decode
+/// high bit depth streams.
+///
+/// Can be reused to save on allocations.
+#[derive(Debug)]
+pub struct Frame<T: Sample = u8> {
+    width: u32,
+    height: u32,
+    sampling: ChromaSampling,
+    pub(crate) bit_depth: u32,
+    pub(crate) meta: FrameMeta,
+    pub(crate) y_plane: Vec<T>,
+    pub(crate) u_plane: Vec<T>,
+    pub(crate) v_plane: Vec<T>,
+}
+
+impl Frame {
+    /// Creates an 8-bit YUV420 frame.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::with_sampling(width, height, ChromaSampling::Cs420)
+    }
+}
+
+impl<T: Sample> Frame<T> {
+    pub fn with_sampling(width: u32, height: u32, sampling: ChromaSampling) -> Self {
+        let mut frame = Self {
+            width: 0,
+            height: 0,
+            sampling,
+            bit_depth: 8 * std::mem::size_of::<T>() as u32,
+            meta: FrameMeta::default(),
+            y_plane: Vec::new(),
+            u_plane: Vec::new(),
+            v_plane: Vec::new(),
+        };
+        frame.resize(width, height);
+        frame
+    }
+
+    /// Changes the dimensions of the frame, reusing the existing
+    /// allocations where possible.
+    ///
+    /// Pixel data is unspecified after resizing.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        let full_size = width as usize * height as usize;
+        let uv_size = self.uv_width() as usize * self.uv_height() as usize;
+        self.y_plane.resize(full_size, T::default());
+        self.u_plane.resize(uv_size, T::default());
+        self.v_plane.resize(uv_size, T::default());
+    }
+
+    /// Changes the chroma subsampling of the frame, resizing the
+    /// U and V planes to match.
+    pub fn set_sampling(&mut self, sampling: ChromaSampling) {
+        self.sampling = sampling;
+        self.resize(self.width, self.height);
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn sampling(&self) -> ChromaSampling {
+        self.sampling
+    }
+
+    /// Number of significant bits per sample, as reported by the decoder
+    /// (`vpx_image.bit_depth`).
+    pub fn bit_depth(&self) -> u32 {
+        self.bit_depth
+    }
+
+    /// The timestamp passed to the `decode` call that produced this frame.
+    pub fn pts(&self) -> u64 {
+        self.meta.pts
+    }
+
+    /// Whether the decoder reported this frame as damaged
+    /// (`VP8D_GET_FRAME_CORRUPTED`), e.g. because of missing references.
+    pub fn is_corrupted(&self) -> bool {
+        self.meta.corrupted
+    }
+
+    /// The size the frame is intended to be displayed at, which VP9
+    /// allows to differ from the decoded size (the header's `render_size`).
+    pub fn display_size(&self) -> (u32, u32) {
+        self.meta.display_size
+    }
+
+    /// Quantizer and reference statistics of the packet the frame was
+    /// decoded from.
+    pub fn stats(&self) -> FrameStats {
+        self.meta.stats
+    }
+
+    pub fn y_plane(&self) -> &[T] {
+        &self.y_plane
+    }
+
+    pub fn u_plane(&self) -> &[T] {
+        &self.u_plane
+    }
+
+    pub fn v_plane(&self) -> &[T] {
+        &self.v_plane
+    }
+
+    pub fn get_y(&self, x: u32, y: u32) -> T {
+        self.y_plane[(x + y * self.width) as usize]
+    }
+
+    /// Returns the U and V samples at chroma plane coordinates `(x, y)`.
+    pub fn get_uv(&self, x: u32, y: u32) -> (T, T) {
+        let index = (x + y * self.uv_width()) as usize;
+        (self.u_plane[index], self.v_plane[index])
+    }
+
+    pub fn uv_width(&self) -> u32 {
+        let shift = self.sampling.x_shift();
+        (self.width + shift) >> shift
+    }
+
+    pub fn uv_height(&self) -> u32 {
+        let shift = self.sampling.y_shift();
+        (self.height + shift) >> shift
+    }
+}
+
+impl<T: Sample> Default for Frame<T> {
+    /// An empty frame, to be sized by the decoder.
+    fn default() -> Self {
+        Self::with_sampling(0, 0, ChromaSampling::Cs420)
+    }
+}
+
+impl Frame<u16> {
+    /// Reduces the frame to 8 bits per sample into `dst`, for SDR previews.
+    ///
+    /// An ordered (Bayer) dither hides the banding that plain truncation
+    /// would cause.
+    pub fn dither_to_8bit(&self, dst: &mut Frame) {
+        if dst.sampling != self.sampling {
+            dst.set_sampling(self.sampling);
+        }
+        if dst.width != self.width || dst.height != self.height {
+            dst.resize(self.width, self.height);
+        }
+        dst.bit_depth = 8;
+        dst.meta = self.meta;
+
+        let shift = self.bit_depth.saturating_sub(8);
+        let uv_width = self.uv_width() as usize;
+        dither_plane(&self.y_plane, &mut dst.y_plane, self.width as usize, shift);
+        dither_plane(&self.u_plane, &mut dst.u_plane, uv_width, shift);
+        dither_plane(&self.v_plane, &mut dst.v_plane, uv_width, shift);
+    }
+}
+
+/// 4x4 Bayer threshold matrix.
+const BAYER: [[u16; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+fn dither_plane(src: &[u16], dst: &mut [u8], width: usize, shift: u32) {
+    if width == 0 {
+        return;
+    }
+    for (y, (src_row, dst_row)) in src
+        .chunks_exact(width)
+        .zip(dst.chunks_exact_mut(width))
+        .enumerate()
+    {
+        for (x, (&sample, out)) in src_row.iter().zip(dst_row).enumerate() {
+            // Scale the threshold from 0..16 to the discarded bit range.
+            let threshold = (BAYER[y % 4][x % 4] << shift) >> 4;
+            *out = ((sample + threshold) >> shift).min(255) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chroma_plane_sizes() {
+        let cases = [
+            (ChromaSampling::Cs420, (17, 9)),
+            (ChromaSampling::Cs422, (17, 17)),
+            (ChromaSampling::Cs440, (33, 9)),
+            (ChromaSampling::Cs444, (33, 17)),
+        ];
+        for (sampling, (uv_width, uv_height)) in cases {
+            let frame = Frame::<u8>::with_sampling(33, 17, sampling);
+            assert_eq!((frame.uv_width(), frame.uv_height()), (uv_width, uv_height));
+            assert_eq!(frame.y_plane().len(), 33 * 17);
+            assert_eq!(frame.u_plane().len(), (uv_width * uv_height) as usize);
+            assert_eq!(frame.v_plane().len(), (uv_width * uv_height) as usize);
+        }
+    }
+
+    #[test]
+    fn set_sampling_resizes_chroma_planes() {
+        let mut frame = Frame::new(10, 7);
+        assert_eq!(frame.u_plane().len(), 5 * 4);
+
+        frame.set_sampling(ChromaSampling::Cs444);
+        assert_eq!(frame.sampling(), ChromaSampling::Cs444);
+        assert_eq!(frame.u_plane().len(), 10 * 7);
+        assert_eq!(frame.y_plane().len(), 10 * 7);
+
+        frame.set_sampling(ChromaSampling::Cs422);
+        frame.resize(3, 1);
+        assert_eq!((frame.uv_width(), frame.uv_height()), (2, 1));
+        assert_eq!(frame.v_plane().len(), 2);
+    }
+
+    #[test]
+    fn get_uv_uses_chroma_coordinates() {
+        let mut frame = Frame::<u16>::with_sampling(5, 3, ChromaSampling::Cs422);
+        for (i, (u, v)) in frame.u_plane.iter_mut().zip(&mut frame.v_plane).enumerate() {
+            *u = i as u16;
+            *v = 100 + i as u16;
+        }
+        // Three chroma samples per row.
+        assert_eq!(frame.get_uv(0, 1), (3, 103));
+        assert_eq!(frame.get_uv(2, 2), (8, 108));
+    }
+
+    /// A high bit depth frame with every sample set to `value`.
+    fn flat(width: u32, height: u32, bit_depth: u32, value: u16) -> Frame<u16> {
+        let mut frame = Frame::<u16>::with_sampling(width, height, ChromaSampling::Cs420);
+        frame.bit_depth = bit_depth;
+        for plane in [&mut frame.y_plane, &mut frame.u_plane, &mut frame.v_plane] {
+            plane.fill(value);
+        }
+        frame
+    }
+
+    #[test]
+    fn dither_keeps_flat_input_within_one_step() {
+        for (bit_depth, value) in [(10, 514), (10, 3), (12, 2050), (12, 4000)] {
+            let expected = i32::from(value >> (bit_depth - 8));
+            let mut dst = Frame::new(0, 0);
+            flat(9, 6, bit_depth, value).dither_to_8bit(&mut dst);
+            assert_eq!(dst.bit_depth(), 8);
+            for &sample in dst.y_plane().iter().chain(dst.u_plane()) {
+                assert!((i32::from(sample) - expected).abs() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn dither_covers_the_output_range() {
+        for bit_depth in [10, 12] {
+            let max = (1 << bit_depth) - 1;
+            let mut dst = Frame::new(0, 0);
+
+            flat(8, 8, bit_depth, 0).dither_to_8bit(&mut dst);
+            assert!(dst.y_plane().iter().all(|&y| y == 0));
+
+            // The largest thresholds would carry the maximum past 255.
+            flat(8, 8, bit_depth, max).dither_to_8bit(&mut dst);
+            assert!(dst.y_plane().iter().all(|&y| y == 255));
+        }
+    }
+
+    #[test]
+    fn dither_sizes_chroma_planes() {
+        let mut src = Frame::<u16>::with_sampling(7, 5, ChromaSampling::Cs422);
+        src.bit_depth = 10;
+        let mut dst = Frame::new(64, 64);
+        src.dither_to_8bit(&mut dst);
+        assert_eq!(dst.sampling(), ChromaSampling::Cs422);
+        assert_eq!((dst.width(), dst.height()), (7, 5));
+        assert_eq!((dst.uv_width(), dst.uv_height()), (4, 5));
+        assert_eq!(dst.u_plane().len(), 4 * 5);
+        assert_eq!(dst.v_plane().len(), 4 * 5);
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    os::raw::{c_int, c_void},
+    ptr, slice,
+    sync::Arc,
+};
+
+use crate::{
+    ffi::{size_t, vpx_codec_frame_buffer},
+    frame::FrameMeta,
+    ChromaSampling, FrameRef, FrameStats, PlaneRef,
+};
+
+/// Memory that libvpx decodes frames into.
+///
+/// # Safety
+/// `as_mut_ptr` must return a pointer to `len` writable bytes that stays
+/// the same for the lifetime of the buffer. The memory must not be
+/// accessed other than through this trait.
+pub unsafe trait FrameBuffer: Send + Sync + 'static {
+    fn as_mut_ptr(&self) -> *mut u8;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Supplies the buffers libvpx decodes frames into, in place of its
+/// internal allocator.
+///
+/// Installed with [`Vp9Decoder::set_frame_buffer_pool`](crate::Vp9Decoder::set_frame_buffer_pool).
+/// VP9 may hold up to `VP9_MAXIMUM_REF_BUFFERS + VPX_MAXIMUM_WORK_BUFFERS`
+/// buffers at once.
+///
+/// # Safety
+/// Buffers returned from `get` must be zero-initialized when they are
+/// first allocated, as libvpx relies on the padding being cleared.
+/// A buffer returned to the pool through `release` may be handed out
+/// again without clearing it.
+pub unsafe trait FrameBufferPool: Send + 'static {
+    type Buffer: FrameBuffer;
+
+    /// Returns a buffer of at least `min_size` bytes, or `None` if
+    /// allocation fails.
+    ///
+    /// The returned `Arc` must not be shared: a buffer still referenced
+    /// elsewhere (e.g. by a [`SharedFrame`]) is rejected.
+    fn get(&mut self, min_size: usize) -> Option<Arc<Self::Buffer>>;
+
+    /// Called when libvpx no longer references `buffer`.
+    ///
+    /// [`SharedFrame`]s may still hold references to it.
+    fn release(&mut self, buffer: Arc<Self::Buffer>) {
+        drop(buffer);
+    }
+}
+
+/// A decoded frame holding a reference to its application-owned
+/// frame buffer, so it outlives later `decode` calls without a copy.
+///
+/// Obtained from [`FrameRef::to_shared`].
+#[derive(Clone)]
+pub struct SharedFrame {
+    buffer: Arc<dyn FrameBuffer>,
+    width: u32,
+    height: u32,
+    sampling: ChromaSampling,
+    bit_depth: u32,
+    meta: FrameMeta,
+    planes: [PlaneLayout; 3],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PlaneLayout {
+    offset: usize,
+    len: usize,
+    stride: usize,
+    width: u32,
+    height: u32,
+    bytes_per_sample: usize,
+}
+
+impl SharedFrame {
+    /// Creates a `SharedFrame` from an image decoded into `buffer`.
+    ///
+    /// Returns `None` if the planes are not inside `buffer`, as happens
+    /// when libvpx outputs a postprocessed copy of the frame.
+    pub(crate) fn new(frame: &FrameRef, buffer: Arc<dyn FrameBuffer>) -> Option<Self> {
+        let base = buffer.as_mut_ptr() as usize;
+        let layout = |plane: PlaneRef| {
+            let offset = (plane.data().as_ptr() as usize).checked_sub(base)?;
+            if offset + plane.data().len() > buffer.len() {
+                return None;
+            }
+            Some(PlaneLayout {
+                offset,
+                len: plane.data().len(),
+                stride: plane.stride(),
+                width: plane.width(),
+                height: plane.height(),
+                bytes_per_sample: plane.bytes_per_sample(),
+            })
+        };
+        let planes = [
+            layout(frame.y_plane())?,
+            layout(frame.u_plane())?,
+            layout(frame.v_plane())?,
+        ];
+
+        Some(Self {
+            buffer,
+            width: frame.width(),
+            height: frame.height(),
+            sampling: frame.sampling(),
+            bit_depth: frame.bit_depth(),
+            meta: frame.meta(),
+            planes,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn sampling(&self) -> ChromaSampling {
+        self.sampling
+    }
+
+    pub fn bit_depth(&self) -> u32 {
+        self.bit_depth
+    }
+
+    pub fn pts(&self) -> u64 {
+        self.meta.pts
+    }
+
+    pub fn is_corrupted(&self) -> bool {
+        self.meta.corrupted
+    }
+
+    pub fn display_size(&self) -> (u32, u32) {
+        self.meta.display_size
+    }
+
+    pub fn stats(&self) -> FrameStats {
+        self.meta.stats
+    }
+
+    pub fn y_plane(&self) -> PlaneRef<'_> {
+        self.plane(0)
+    }
+
+    pub fn u_plane(&self) -> PlaneRef<'_> {
+        self.plane(1)
+    }
+
+    pub fn v_plane(&self) -> PlaneRef<'_> {
+        self.plane(2)
+    }
+
+    /// The underlying frame buffer.
+    pub fn buffer(&self) -> &Arc<dyn FrameBuffer> {
+        &self.buffer
+    }
+
+    fn plane(&self, index: usize) -> PlaneRef<'_> {
+        let layout = self.planes[index];
+        // libvpx no longer writes to a frame once it has been output.
+        let data = unsafe {
+            slice::from_raw_parts(self.buffer.as_mut_ptr().add(layout.offset), layout.len)
+        };
+        PlaneRef::new(
+            data,
+            layout.stride,
+            layout.width,
+            layout.height,
+            layout.bytes_per_sample,
+        )
+    }
+}
+
+/// `vpx_get_frame_buffer_cb_fn_t` for a pool of type `P`.
+///
+/// `fb.priv_` is set to a boxed `Arc<dyn FrameBuffer>` so that output
+/// images can be turned into [`SharedFrame`]s without knowing `P`.
+pub(crate) unsafe extern "C" fn get_frame_buffer<P: FrameBufferPool>(
+    pool: *mut c_void,
+    min_size: size_t,
+    fb: *mut vpx_codec_frame_buffer,
+) -> c_int {
+    let pool = &mut *(pool as *mut P);
+    let mut buffer = match pool.get(min_size as usize) {
+        Some(buffer) => buffer,
+        None => return -1,
+    };
+    if Arc::get_mut(&mut buffer).is_none() || buffer.len() < min_size as usize {
+        pool.release(buffer);
+        return -1;
+    }
+
+    let fb = &mut *fb;
+    fb.data = buffer.as_mut_ptr();
+    fb.size = buffer.len() as size_t;
+    let buffer: Arc<dyn FrameBuffer> = buffer;
+    fb.priv_ = Box::into_raw(Box::new(buffer)) as *mut c_void;
+    0
+}
+
+/// `vpx_release_frame_buffer_cb_fn_t` for a pool of type `P`.
+pub(crate) unsafe extern "C" fn release_frame_buffer<P: FrameBufferPool>(
+    pool: *mut c_void,
+    fb: *mut vpx_codec_frame_buffer,
+) -> c_int {
+    let fb = &mut *fb;
+    if fb.priv_.is_null() {
+        return 0;
+    }
+    let buffer = *Box::from_raw(fb.priv_ as *mut Arc<dyn FrameBuffer>);
+    fb.priv_ = ptr::null_mut();
+
+    // Only `get_frame_buffer::<P>` creates these, so the concrete type is known.
+    let buffer = Arc::from_raw(Arc::into_raw(buffer) as *const P::Buffer);
+    let pool = &mut *(pool as *mut P);
+    pool.release(buffer);
+    0
+}
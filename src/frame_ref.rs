@@ -0,0 +1,195 @@
+use std::{slice, sync::Arc};
+
+use crate::{
+    ffi::{vpx_image, VPX_IMG_FMT_HIGHBITDEPTH},
+    frame::FrameMeta,
+    ChromaSampling, Error, FrameBuffer, FrameStats, SharedFrame,
+};
+
+/// A decoded image borrowed directly from libvpx, without copying.
+///
+/// Obtained from [`Vp9Decoder::next_frame_ref`](crate::Vp9Decoder::next_frame_ref).
+/// The borrow ends before the next call on the decoder, which may reuse the memory.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameRef<'a> {
+    img: &'a vpx_image,
+    sampling: ChromaSampling,
+    meta: FrameMeta,
+    external_buffer: bool,
+}
+
+impl<'a> FrameRef<'a> {
+    /// `external_buffer` is set if the image lives in a buffer from a
+    /// [`FrameBufferPool`](crate::FrameBufferPool).
+    pub(crate) fn new(
+        img: &'a vpx_image,
+        meta: FrameMeta,
+        external_buffer: bool,
+    ) -> Result<Self, Error> {
+        let sampling =
+            ChromaSampling::from_img_fmt(img.fmt).ok_or(Error::UnsupportedImageFormat(img.fmt))?;
+        Ok(Self {
+            img,
+            sampling,
+            meta,
+            external_buffer,
+        })
+    }
+
+    /// Takes a reference to the frame buffer the image was decoded into,
+    /// so the frame can be kept without copying.
+    ///
+    /// Returns `None` unless the decoder has a
+    /// [`FrameBufferPool`](crate::FrameBufferPool), or if the image is
+    /// not in a pool buffer, e.g. because postprocessing is enabled.
+    pub fn to_shared(&self) -> Option<SharedFrame> {
+        if !self.external_buffer || self.img.fb_priv.is_null() {
+            return None;
+        }
+        let buffer = unsafe { &*(self.img.fb_priv as *const Arc<dyn FrameBuffer>) };
+        SharedFrame::new(self, Arc::clone(buffer))
+    }
+
+    pub fn width(&self) -> u32 {
+        self.img.d_w
+    }
+
+    pub fn height(&self) -> u32 {
+        self.img.d_h
+    }
+
+    pub fn sampling(&self) -> ChromaSampling {
+        self.sampling
+    }
+
+    pub fn bit_depth(&self) -> u32 {
+        self.img.bit_depth
+    }
+
+    /// The timestamp passed to the `decode` call that produced this frame.
+    pub fn pts(&self) -> u64 {
+        self.meta.pts
+    }
+
+    /// Whether the decoder reported this frame as damaged
+    /// (`VP8D_GET_FRAME_CORRUPTED`).
+    pub fn is_corrupted(&self) -> bool {
+        self.meta.corrupted
+    }
+
+    /// The intended display size, from the frame header's `render_size`.
+    pub fn display_size(&self) -> (u32, u32) {
+        self.meta.display_size
+    }
+
+    pub fn stats(&self) -> FrameStats {
+        self.meta.stats
+    }
+
+    pub(crate) fn meta(&self) -> FrameMeta {
+        self.meta
+    }
+
+    /// Whether samples are stored as native-endian `u16`s rather than bytes.
+    pub fn is_high_bitdepth(&self) -> bool {
+        self.img.fmt & VPX_IMG_FMT_HIGHBITDEPTH != 0
+    }
+
+    pub fn y_plane(&self) -> PlaneRef<'a> {
+        self.plane(0, self.width(), self.height())
+    }
+
+    pub fn u_plane(&self) -> PlaneRef<'a> {
+        self.plane(1, self.uv_width(), self.uv_height())
+    }
+
+    pub fn v_plane(&self) -> PlaneRef<'a> {
+        self.plane(2, self.uv_width(), self.uv_height())
+    }
+
+    pub fn uv_width(&self) -> u32 {
+        let shift = self.sampling.x_shift();
+        (self.width() + shift) >> shift
+    }
+
+    pub fn uv_height(&self) -> u32 {
+        let shift = self.sampling.y_shift();
+        (self.height() + shift) >> shift
+    }
+
+    fn plane(&self, index: usize, width: u32, height: u32) -> PlaneRef<'a> {
+        let bytes_per_sample = if self.is_high_bitdepth() { 2 } else { 1 };
+        let stride = self.img.stride[index] as usize;
+        let row_len = width as usize * bytes_per_sample;
+        // The last row is not necessarily padded out to the full stride.
+        let len = match height {
+            0 => 0,
+            height => (height as usize - 1) * stride + row_len,
+        };
+        let data = unsafe { slice::from_raw_parts(self.img.planes[index], len) };
+        PlaneRef::new(data, stride, width, height, bytes_per_sample)
+    }
+}
+
+/// One plane of a [`FrameRef`], including the row padding libvpx allocates.
+#[derive(Debug, Clone, Copy)]
+pub struct PlaneRef<'a> {
+    data: &'a [u8],
+    stride: usize,
+    width: u32,
+    height: u32,
+    bytes_per_sample: usize,
+}
+
+impl<'a> PlaneRef<'a> {
+    pub(crate) fn new(
+        data: &'a [u8],
+        stride: usize,
+        width: u32,
+        height: u32,
+        bytes_per_sample: usize,
+    ) -> Self {
+        Self {
+            data,
+            stride,
+            width,
+            height,
+            bytes_per_sample,
+        }
+    }
+
+    /// The raw plane memory, `stride` bytes per row.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Distance between the starts of consecutive rows, in bytes.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Width in samples.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn bytes_per_sample(&self) -> usize {
+        self.bytes_per_sample
+    }
+
+    /// Returns row `y` without its padding.
+    pub fn row(&self, y: u32) -> &'a [u8] {
+        let start = y as usize * self.stride;
+        &self.data[start..start + self.width as usize * self.bytes_per_sample]
+    }
+
+    /// Iterates over the rows of the plane without their padding.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        let plane = *self;
+        (0..self.height).map(move |y| plane.row(y))
+    }
+}
@@ -0,0 +1,80 @@
+/// Coding statistics of a decoded packet.
+///
+/// libvpx reports these once per [`decode`](crate::Vp9Decoder::decode)
+/// call, so they describe the last frame of a superframe, and every frame
+/// output for the packet reports the same values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameStats {
+    /// The frame's base quantizer index, `0..=255` (`VPXD_GET_LAST_QUANTIZER`).
+    /// Higher values mean coarser quantization and fewer bits.
+    pub quantizer: u32,
+    /// Bitmask of the reference buffer slots the frame refreshed
+    /// (`VP8D_GET_LAST_REF_UPDATES`).
+    pub ref_updates: u8,
+    /// Bitmask of the references the frame predicted from
+    /// (`VP8D_GET_LAST_REF_USED`), if the linked libvpx reports it for VP9.
+    pub refs_used: Option<u8>,
+}
+
+/// Statistics aggregated over every packet a decoder has decoded.
+///
+/// Obtained from [`Vp9Decoder::stream_stats`](crate::Vp9Decoder::stream_stats).
+#[derive(Debug, Clone, Default)]
+pub struct StreamStats {
+    frames: u64,
+    min_quantizer: u32,
+    max_quantizer: u32,
+    quantizer_sum: u64,
+    refresh_counts: [u64; 8],
+    non_reference_frames: u64,
+}
+
+impl StreamStats {
+    pub(crate) fn record(&mut self, stats: &FrameStats) {
+        if self.frames == 0 {
+            self.min_quantizer = stats.quantizer;
+            self.max_quantizer = stats.quantizer;
+        } else {
+            self.min_quantizer = self.min_quantizer.min(stats.quantizer);
+            self.max_quantizer = self.max_quantizer.max(stats.quantizer);
+        }
+        self.frames += 1;
+        self.quantizer_sum += u64::from(stats.quantizer);
+
+        for (slot, count) in self.refresh_counts.iter_mut().enumerate() {
+            if stats.ref_updates & (1 << slot) != 0 {
+                *count += 1;
+            }
+        }
+        if stats.ref_updates == 0 {
+            self.non_reference_frames += 1;
+        }
+    }
+
+    /// Number of packets decoded, counting a superframe once.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    pub fn min_quantizer(&self) -> Option<u32> {
+        (self.frames > 0).then_some(self.min_quantizer)
+    }
+
+    pub fn max_quantizer(&self) -> Option<u32> {
+        (self.frames > 0).then_some(self.max_quantizer)
+    }
+
+    pub fn mean_quantizer(&self) -> Option<f64> {
+        (self.frames > 0).then(|| self.quantizer_sum as f64 / self.frames as f64)
+    }
+
+    /// How many packets refreshed each of the eight reference slots.
+    pub fn refresh_counts(&self) -> [u64; 8] {
+        self.refresh_counts
+    }
+
+    /// Number of packets that refreshed no reference slot.
+    pub fn non_reference_frames(&self) -> u64 {
+        self.non_reference_frames
+    }
+}
@@ -0,0 +1,58 @@
+use std::mem;
+
+use crate::{
+    ffi::{
+        vpx_codec_err_t_VPX_CODEC_OK, vpx_codec_peek_stream_info, vpx_codec_stream_info,
+        vpx_codec_vp9_dx,
+    },
+    Error,
+};
+
+/// Properties of a stream read from a packet's frame header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StreamInfo {
+    /// Frame width, or `0` if the packet does not carry it (inter frames).
+    pub width: u32,
+    /// Frame height, or `0` if the packet does not carry it (inter frames).
+    pub height: u32,
+    pub is_keyframe: bool,
+}
+
+impl StreamInfo {
+    pub(crate) fn empty_ffi() -> vpx_codec_stream_info {
+        vpx_codec_stream_info {
+            sz: mem::size_of::<vpx_codec_stream_info>() as u32,
+            w: 0,
+            h: 0,
+            is_kf: 0,
+        }
+    }
+
+    pub(crate) fn from_ffi(info: &vpx_codec_stream_info) -> Self {
+        Self {
+            width: info.w,
+            height: info.h,
+            is_keyframe: info.is_kf != 0,
+        }
+    }
+}
+
+/// Parses the frame header of a packet without creating a decoder
+/// (`vpx_codec_peek_stream_info`), e.g. to validate an upload from
+/// its first packet.
+pub fn peek_stream_info(data: &[u8]) -> Result<StreamInfo, Error> {
+    let mut info = StreamInfo::empty_ffi();
+    let ret = unsafe {
+        vpx_codec_peek_stream_info(
+            vpx_codec_vp9_dx(),
+            data.as_ptr(),
+            data.len().try_into().unwrap(),
+            &mut info,
+        )
+    };
+
+    if ret != vpx_codec_err_t_VPX_CODEC_OK {
+        return Err(Error::from_code(ret));
+    }
+    Ok(StreamInfo::from_ffi(&info))
+}
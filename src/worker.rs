@@ -0,0 +1,135 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread::{self, JoinHandle},
+};
+
+use crate::{frame::Sample, Error, Frame, Vp9Decoder};
+
+enum Message {
+    Packet { data: Vec<u8>, pts: u64 },
+    Flush,
+}
+
+/// What a [`DecoderWorker`] returns for each packet and flush.
+#[derive(Debug)]
+pub enum WorkerOutput<T: Sample = u8> {
+    /// A decoded frame.
+    Frame(Frame<T>),
+    /// The packet or flush has been handled and produced `frames` frames,
+    /// which were returned before this. Sent exactly once per
+    /// [`send`](DecoderWorker::send) and [`flush`](DecoderWorker::flush),
+    /// also after an error and for packets without output, such as hidden
+    /// frames.
+    Decoded { frames: usize },
+}
+
+/// Runs a [`Vp9Decoder`] on a background thread, taking packets and
+/// returning decoded frames over channels.
+///
+/// Receiving until [`WorkerOutput::Decoded`] collects everything one
+/// packet produced without waiting on the next.
+///
+/// Dropping the worker waits for it to finish the packets already sent.
+pub struct DecoderWorker<T: Sample = u8> {
+    packets: Option<Sender<Message>>,
+    frames: Receiver<Result<WorkerOutput<T>, Error>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<T: Sample> DecoderWorker<T> {
+    /// Moves `decoder` to a new thread.
+    pub fn new(decoder: Vp9Decoder) -> Self {
+        let (packet_sender, packet_receiver) = mpsc::channel();
+        let (frame_sender, frame_receiver) = mpsc::channel();
+        let thread = thread::Builder::new()
+            .name("vp9-decoder".to_owned())
+            .spawn(move || run(decoder, packet_receiver, frame_sender))
+            .expect("failed to spawn decoder thread");
+
+        Self {
+            packets: Some(packet_sender),
+            frames: frame_receiver,
+            thread: Some(thread),
+        }
+    }
+
+    /// Queues a packet for decoding; see [`Vp9Decoder::decode`].
+    pub fn send(&self, data: Vec<u8>, pts: u64) -> Result<(), Error> {
+        self.post(Message::Packet { data, pts })
+    }
+
+    /// Queues a flush at the end of the stream; see [`Vp9Decoder::flush`].
+    pub fn flush(&self) -> Result<(), Error> {
+        self.post(Message::Flush)
+    }
+
+    /// Waits for the next decoded frame or end-of-packet marker, or the
+    /// error from a failed `decode`. Returns `None` if the worker thread
+    /// has stopped.
+    pub fn recv(&self) -> Option<Result<WorkerOutput<T>, Error>> {
+        self.frames.recv().ok()
+    }
+
+    /// Returns the next output if one is ready.
+    pub fn try_recv(&self) -> Option<Result<WorkerOutput<T>, Error>> {
+        match self.frames.try_recv() {
+            Ok(frame) => Some(frame),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(Error::WorkerStopped)),
+        }
+    }
+
+    fn post(&self, message: Message) -> Result<(), Error> {
+        self.packets
+            .as_ref()
+            .and_then(|packets| packets.send(message).ok())
+            .ok_or(Error::WorkerStopped)
+    }
+}
+
+fn run<T: Sample>(
+    mut decoder: Vp9Decoder,
+    packets: Receiver<Message>,
+    outputs: Sender<Result<WorkerOutput<T>, Error>>,
+) {
+    for message in packets {
+        let result = match message {
+            Message::Packet { data, pts } => decoder.decode(&data, pts),
+            Message::Flush => decoder.flush(),
+        };
+
+        let mut frames = 0;
+        let mut error = result.err();
+        while error.is_none() {
+            let mut frame = Frame::default();
+            match decoder.next_frame(&mut frame) {
+                Ok(true) => {
+                    frames += 1;
+                    if outputs.send(Ok(WorkerOutput::Frame(frame))).is_err() {
+                        return;
+                    }
+                }
+                Ok(false) => break,
+                Err(e) => error = Some(e),
+            }
+        }
+        if let Some(e) = error {
+            if outputs.send(Err(e)).is_err() {
+                return;
+            }
+        }
+        if outputs.send(Ok(WorkerOutput::Decoded { frames })).is_err() {
+            return;
+        }
+    }
+}
+
+impl<T: Sample> Drop for DecoderWorker<T> {
+    fn drop(&mut self) {
+        // Closing the channel ends the thread's loop.
+        self.packets.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Synthesizes small VP9 streams for the integration tests.
+//!
+//! Every coded frame is a profile 0 keyframe whose compressed header and
+//! tile data consist of zero bytes. A bool decoder reads zero bytes as zero
+//! bits regardless of the probabilities in use, which selects `ONLY_4X4`
+//! transforms, no probability updates, `PARTITION_NONE` (or the partition
+//! forced at the frame edge) and `DC_PRED`. Segment 0 carries the skip
+//! feature, so no residual is coded and frames decode to flat mid-gray.
+
+#![allow(dead_code)]
+
+/// Value of every sample in a synthesized frame.
+pub const GRAY: u8 = 128;
+
+/// A keyframe of flat gray.
+#[derive(Debug, Clone, Copy)]
+pub struct KeyFrame {
+    width: u32,
+    height: u32,
+    render_size: Option<(u32, u32)>,
+    show: bool,
+    base_q_idx: u8,
+    filter_level: u8,
+}
+
+impl KeyFrame {
+    pub fn new(width: u32, height: u32) -> Self {
+        assert!((1..=1 << 16).contains(&width) && (1..=1 << 16).contains(&height));
+        Self {
+            width,
+            height,
+            render_size: None,
+            show: true,
+            base_q_idx: 60,
+            filter_level: 8,
+        }
+    }
+
+    /// Marks the frame as not shown, e.g. for the hidden frame of a superframe.
+    pub fn hidden(mut self) -> Self {
+        self.show = false;
+        self
+    }
+
+    /// Signals a display size different from the coded size.
+    pub fn render_size(mut self, width: u32, height: u32) -> Self {
+        self.render_size = Some((width, height));
+        self
+    }
+
+    pub fn base_q_idx(mut self, base_q_idx: u8) -> Self {
+        // Index 0 would make the frame lossless, which changes the header.
+        assert_ne!(base_q_idx, 0);
+        self.base_q_idx = base_q_idx;
+        self
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mi_cols = self.width.div_ceil(8);
+        let mi_rows = self.height.div_ceil(8);
+        let sb_cols = mi_cols.div_ceil(8);
+
+        // As few tile columns as VP9 allows.
+        let tile_cols_log2 = (0..).find(|&log2| 64 << log2 >= sb_cols).unwrap();
+        let max_log2_tile_cols = (1..).find(|&log2| sb_cols >> log2 < 4).unwrap() - 1;
+
+        // Seven reads: the marker bit, `tx_mode`, the 4x4 coefficient
+        // update flag and three skip probability updates.
+        let compressed_header = zero_bits_for_reads(7);
+
+        let mut writer = BitWriter::default();
+        writer.write(2, 2); // frame_marker
+        writer.write(0, 2); // profile 0
+        writer.write(0, 1); // show_existing_frame
+        writer.write(0, 1); // frame_type: KEY_FRAME
+        writer.write(self.show as u32, 1);
+        writer.write(0, 1); // error_resilient_mode
+        writer.write(0x49_83_42, 24); // frame_sync_code
+        writer.write(1, 3); // color_space: BT.601
+        writer.write(0, 1); // color_range
+        writer.write(self.width - 1, 16);
+        writer.write(self.height - 1, 16);
+        match self.render_size {
+            Some((width, height)) => {
+                writer.write(1, 1);
+                writer.write(width - 1, 16);
+                writer.write(height - 1, 16);
+            }
+            None => writer.write(0, 1),
+        }
+        writer.write(0, 1); // refresh_frame_context
+        writer.write(1, 1); // frame_parallel_decoding_mode
+        writer.write(0, 2); // frame_context_idx
+
+        writer.write(self.filter_level as u32, 6);
+        writer.write(0, 3); // loop_filter_sharpness
+        writer.write(0, 1); // loop_filter_delta_enabled
+
+        writer.write(self.base_q_idx as u32, 8);
+        writer.write(0, 3); // no delta_q_y_dc, delta_q_uv_dc, delta_q_uv_ac
+
+        writer.write(1, 1); // segmentation_enabled
+        writer.write(0, 1); // segmentation_update_map: every block is in segment 0
+        writer.write(1, 1); // segmentation_update_data
+        writer.write(0, 1); // segmentation_abs_or_delta_update
+        for segment in 0..8 {
+            writer.write(0, 3); // no ALT_Q, ALT_LF or REF_FRAME
+            writer.write((segment == 0) as u32, 1); // SKIP, which has no data
+        }
+
+        if tile_cols_log2 < max_log2_tile_cols {
+            writer.write(0, 1); // no increment_tile_cols_log2
+        }
+        writer.write(0, 1); // tile_rows_log2
+
+        writer.write(compressed_header.len() as u32, 16);
+
+        let mut data = writer.finish();
+        data.extend_from_slice(&compressed_header);
+
+        let tile_cols = 1 << tile_cols_log2;
+        for tile in 0..tile_cols {
+            let start = tile_offset(tile, mi_cols, tile_cols_log2);
+            let end = tile_offset(tile + 1, mi_cols, tile_cols_log2);
+            let mut reads = 1; // marker bit
+            for mi_row in (0..mi_rows).step_by(8) {
+                for mi_col in (start..end).step_by(8) {
+                    reads += partition_reads(mi_row, mi_col, 3, mi_rows, mi_cols);
+                }
+            }
+            let tile_data = zero_bits_for_reads(reads);
+            if tile != tile_cols - 1 {
+                data.extend_from_slice(&(tile_data.len() as u32).to_be_bytes());
+            }
+            data.extend_from_slice(&tile_data);
+        }
+        data
+    }
+}
+
+/// A frame header that shows reference slot `slot` again without decoding.
+pub fn show_existing_frame(slot: u8) -> Vec<u8> {
+    assert!(slot < 8);
+    // frame_marker, profile 0, show_existing_frame, frame_to_show_map_idx
+    vec![0b1000_1000 | slot]
+}
+
+/// Joins `frames` into a superframe with an index (bitstream spec, Annex B).
+pub fn superframe(frames: &[Vec<u8>]) -> Vec<u8> {
+    assert!((1..=8).contains(&frames.len()));
+    let marker = 0b1100_0000 | (3 << 3) | (frames.len() as u8 - 1);
+
+    let mut data: Vec<u8> = frames.concat();
+    data.push(marker);
+    for frame in frames {
+        data.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+    }
+    data.push(marker);
+    data
+}
+
+/// Writes `packets` as an IVF file with one timestamp per packet.
+pub fn ivf(width: u32, height: u32, packets: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(b"DKIF");
+    data.extend_from_slice(&0u16.to_le_bytes()); // version
+    data.extend_from_slice(&32u16.to_le_bytes()); // header size
+    data.extend_from_slice(b"VP90");
+    data.extend_from_slice(&(width as u16).to_le_bytes());
+    data.extend_from_slice(&(height as u16).to_le_bytes());
+    data.extend_from_slice(&30u32.to_le_bytes()); // time base denominator
+    data.extend_from_slice(&1u32.to_le_bytes()); // time base numerator
+    data.extend_from_slice(&(packets.len() as u32).to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+
+    for (timestamp, packet) in packets.iter().enumerate() {
+        data.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+        data.extend_from_slice(&(timestamp as u64).to_le_bytes());
+        data.extend_from_slice(packet);
+    }
+    data
+}
+
+/// Number of bool decoder reads for the partition tree of the block at
+/// `(mi_row, mi_col)` with `1 << bsl` 8x8 blocks per side.
+fn partition_reads(mi_row: u32, mi_col: u32, bsl: u32, mi_rows: u32, mi_cols: u32) -> usize {
+    if mi_row >= mi_rows || mi_col >= mi_cols {
+        return 0;
+    }
+    let half = (1 << bsl) >> 1;
+    let has_rows = mi_row + half < mi_rows;
+    let has_cols = mi_col + half < mi_cols;
+    if has_rows || has_cols {
+        // A zero bit picks PARTITION_NONE, or PARTITION_HORZ / PARTITION_VERT
+        // at the bottom / right edge. The single block coded then reads its
+        // luma and chroma modes.
+        return 3;
+    }
+    // PARTITION_SPLIT is implied.
+    [(0, 0), (0, half), (half, 0), (half, half)]
+        .iter()
+        .map(|&(row, col)| partition_reads(mi_row + row, mi_col + col, bsl - 1, mi_rows, mi_cols))
+        .sum()
+}
+
+/// Zero bytes enough for `reads` bool decoder reads.
+///
+/// Reading a zero bit consumes at most 7 bits of input, however skewed the
+/// probability. libvpx also wants one byte beyond what it consumes.
+fn zero_bits_for_reads(reads: usize) -> Vec<u8> {
+    vec![0; (reads * 7).div_ceil(8) + 2]
+}
+
+/// First 8x8 column of tile column `tile` (`get_tile_offset` in libvpx).
+fn tile_offset(tile: u32, mi_cols: u32, tile_cols_log2: u32) -> u32 {
+    let sb_cols = mi_cols.div_ceil(8);
+    (((tile * sb_cols) >> tile_cols_log2) * 8).min(mi_cols)
+}
+
+/// Writes the uncompressed header, most significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    data: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        assert!(bits == 32 || value >> bits == 0);
+        for bit in (0..bits).rev() {
+            if self.bits.is_multiple_of(8) {
+                self.data.push(0);
+            }
+            let byte = self.data.last_mut().unwrap();
+            *byte |= (((value >> bit) & 1) as u8) << (7 - self.bits % 8);
+            self.bits += 1;
+        }
+    }
+
+    /// Returns the header padded with zero bits to a whole byte.
+    fn finish(self) -> Vec<u8> {
+        self.data
+    }
+}
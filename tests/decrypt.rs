@@ -0,0 +1,102 @@
+#![cfg(feature = "aes-ctr")]
+
+mod common;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use common::{show_existing_frame, superframe, KeyFrame, GRAY};
+use vp9::{Aes128CtrDecryptor, Frame, Subsample, Vp9Decoder};
+
+const KEY: [u8; 16] = *b"0123456789abcdef";
+const IV: [u8; 16] = [
+    0xa5, 0x5a, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Encrypts the encrypted runs of `subsamples` as one keystream, or the
+/// whole packet without subsamples.
+fn encrypt(packet: &[u8], subsamples: &[Subsample]) -> Vec<u8> {
+    let mut cipher = ctr::Ctr128BE::<aes::Aes128>::new(&KEY.into(), &IV.into());
+    let mut data = packet.to_vec();
+    if subsamples.is_empty() {
+        cipher.apply_keystream(&mut data);
+        return data;
+    }
+
+    let mut pos = 0;
+    for subsample in subsamples {
+        pos += subsample.clear_bytes;
+        cipher.apply_keystream(&mut data[pos..pos + subsample.encrypted_bytes]);
+        pos += subsample.encrypted_bytes;
+    }
+    assert_eq!(pos, packet.len());
+    data
+}
+
+/// Decrypts and decodes `packet`, returning the single frame it produces.
+fn decode_encrypted(packet: &[u8], subsamples: &[Subsample]) -> Frame {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.set_decryptor(Aes128CtrDecryptor::new(KEY)).unwrap();
+    decoder
+        .decryptor_mut::<Aes128CtrDecryptor>()
+        .unwrap()
+        .set_packet(IV, subsamples);
+
+    let encrypted = encrypt(packet, subsamples);
+    assert_ne!(encrypted, packet);
+    decoder.decode(&encrypted, 0).unwrap();
+
+    let mut frame = Frame::default();
+    assert!(decoder.next_frame(&mut frame).unwrap());
+    assert!(!decoder.next_frame(&mut frame).unwrap());
+    frame
+}
+
+fn assert_gray(frame: &Frame, width: u32, height: u32) {
+    assert_eq!((frame.width(), frame.height()), (width, height));
+    assert!(frame.y_plane().iter().all(|&y| y == GRAY));
+    assert!(frame.u_plane().iter().all(|&u| u == GRAY));
+    assert!(frame.v_plane().iter().all(|&v| v == GRAY));
+}
+
+#[test]
+fn decrypts_whole_packet() {
+    let packet = KeyFrame::new(96, 40).encode();
+    assert_gray(&decode_encrypted(&packet, &[]), 96, 40);
+}
+
+#[test]
+fn decrypts_subsamples() {
+    let packet = KeyFrame::new(96, 40).encode();
+    // The first bytes of the header stay clear, as in WebM encryption.
+    let subsamples = [Subsample {
+        clear_bytes: 8,
+        encrypted_bytes: packet.len() - 8,
+    }];
+    assert_gray(&decode_encrypted(&packet, &subsamples), 96, 40);
+}
+
+#[test]
+fn decrypts_several_subsamples_as_one_keystream() {
+    let packet = KeyFrame::new(64, 64).encode();
+    let subsamples = [
+        Subsample {
+            clear_bytes: 3,
+            encrypted_bytes: 10,
+        },
+        Subsample {
+            clear_bytes: 5,
+            encrypted_bytes: packet.len() - 18,
+        },
+    ];
+    assert_gray(&decode_encrypted(&packet, &subsamples), 64, 64);
+}
+
+#[test]
+fn decrypts_superframe_index() {
+    // libvpx reads the index at the end of the packet through the
+    // decryptor as well.
+    let packet = superframe(&[
+        KeyFrame::new(64, 64).hidden().encode(),
+        show_existing_frame(0),
+    ]);
+    assert_gray(&decode_encrypted(&packet, &[]), 64, 64);
+}
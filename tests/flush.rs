@@ -0,0 +1,99 @@
+mod common;
+
+use std::{env, fs, io::Cursor};
+
+use common::{show_existing_frame, superframe, KeyFrame};
+use vp9::{ivf::IvfDemuxer, Frame, Vp9Decoder, Vp9DecoderConfig};
+
+/// Ten packets that each produce one frame, including a resolution
+/// change and superframes with a hidden frame.
+const FIXTURE: &[u8] = include_bytes!("fixtures/gray.ivf");
+const FIXTURE_PATH: &str = "tests/fixtures/gray.ivf";
+
+fn fixture_packets() -> Vec<Vec<u8>> {
+    let hidden_then_shown = |width, height| {
+        superframe(&[
+            KeyFrame::new(width, height).hidden().encode(),
+            show_existing_frame(0),
+        ])
+    };
+    vec![
+        KeyFrame::new(64, 64).encode(),
+        KeyFrame::new(64, 64).base_q_idx(100).encode(),
+        hidden_then_shown(64, 64),
+        show_existing_frame(0),
+        KeyFrame::new(96, 40).encode(),
+        hidden_then_shown(32, 32),
+        KeyFrame::new(64, 64).encode(),
+        KeyFrame::new(64, 64).base_q_idx(200).encode(),
+        hidden_then_shown(64, 64),
+        KeyFrame::new(64, 64).encode(),
+    ]
+}
+
+/// Decodes the fixture, draining the decoder with `flush` at the end, and
+/// returns the timestamps of the frames output.
+fn decode_fixture(config: Vp9DecoderConfig) -> (usize, Vec<u64>) {
+    let mut demuxer = IvfDemuxer::new(Cursor::new(FIXTURE)).unwrap();
+    let mut decoder = config.build().unwrap();
+    let mut frame: Frame = Frame::default();
+    let mut packets = 0;
+    let mut timestamps = Vec::new();
+
+    while let Some(packet) = demuxer.next_frame().unwrap() {
+        decoder.decode(packet.data, packet.timestamp).unwrap();
+        packets += 1;
+        while decoder.next_frame(&mut frame).unwrap() {
+            timestamps.push(frame.pts());
+        }
+    }
+    decoder.flush().unwrap();
+    while decoder.next_frame(&mut frame).unwrap() {
+        timestamps.push(frame.pts());
+    }
+    (packets, timestamps)
+}
+
+#[test]
+fn fixture_matches_generator() {
+    let packets = fixture_packets();
+    let ivf = common::ivf(64, 64, &packets);
+    if env::var_os("VP9_REGENERATE_FIXTURES").is_some() {
+        fs::write(FIXTURE_PATH, &ivf).unwrap();
+    } else {
+        assert!(
+            ivf == FIXTURE,
+            "set VP9_REGENERATE_FIXTURES to update {FIXTURE_PATH}"
+        );
+    }
+}
+
+#[test]
+fn flush_yields_one_frame_per_packet() {
+    let (packets, timestamps) = decode_fixture(Vp9Decoder::builder());
+    assert_eq!(packets, fixture_packets().len());
+    assert_eq!(timestamps, (0..packets as u64).collect::<Vec<_>>());
+}
+
+#[test]
+fn flush_yields_one_frame_per_packet_with_frame_threading() {
+    let config = Vp9Decoder::builder().threads(4).frame_threading(true);
+    let (packets, timestamps) = decode_fixture(config);
+    assert_eq!(timestamps.len(), packets);
+    assert_eq!(timestamps, (0..packets as u64).collect::<Vec<_>>());
+}
+
+#[test]
+fn decoding_resumes_after_flush() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let mut frame: Frame = Frame::default();
+    for pts in 0..2 {
+        decoder
+            .decode(&KeyFrame::new(64, 64).encode(), pts)
+            .unwrap();
+        assert!(decoder.next_frame(&mut frame).unwrap());
+        assert_eq!(frame.pts(), pts);
+        decoder.flush().unwrap();
+        assert!(!decoder.next_frame(&mut frame).unwrap());
+    }
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::KeyFrame;
+use vp9::{Error, Frame, Vp9Decoder};
+
+fn assert_not_enabled(result: Result<(), Error>) {
+    match result {
+        Err(Error::InvalidParam(details)) => assert_eq!(
+            details.message.as_deref(),
+            Some("input fragments were not enabled")
+        ),
+        other => panic!("expected InvalidParam, got {other:?}"),
+    }
+}
+
+#[test]
+fn fragments_require_input_fragments() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let packet = KeyFrame::new(64, 64).encode();
+    assert_not_enabled(decoder.decode_fragment(&packet));
+    assert_not_enabled(decoder.end_frame(0));
+
+    // Neither call reached libvpx, so whole packets still decode.
+    decoder.decode(&packet, 1).unwrap();
+    let mut frame: Frame = Frame::default();
+    assert!(decoder.next_frame(&mut frame).unwrap());
+    assert_eq!(frame.pts(), 1);
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::{
+    cell::UnsafeCell,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use common::{KeyFrame, GRAY};
+use vp9::{FrameBuffer, FrameBufferPool, PlaneRef, SharedFrame, Vp9Decoder};
+
+/// Written over every buffer the pool hands out again.
+const POISON: u8 = 0x55;
+
+struct VecBuffer {
+    data: UnsafeCell<Vec<u8>>,
+    /// Number of buffers not yet dropped.
+    live: Arc<AtomicUsize>,
+}
+
+impl Drop for VecBuffer {
+    fn drop(&mut self) {
+        self.live.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// The pool and libvpx only access the memory through the pointer.
+unsafe impl Sync for VecBuffer {}
+
+unsafe impl FrameBuffer for VecBuffer {
+    fn as_mut_ptr(&self) -> *mut u8 {
+        unsafe { (*self.data.get()).as_mut_ptr() }
+    }
+
+    fn len(&self) -> usize {
+        unsafe { (*self.data.get()).len() }
+    }
+}
+
+/// Reuses released buffers once nothing else references them, poisoning
+/// them first, so a frame that is still in use would be overwritten.
+#[derive(Default)]
+struct VecPool {
+    free: Vec<Arc<VecBuffer>>,
+    live: Arc<AtomicUsize>,
+    /// Hands out buffers it keeps a reference to, which must be rejected.
+    share: bool,
+}
+
+unsafe impl FrameBufferPool for VecPool {
+    type Buffer = VecBuffer;
+
+    fn get(&mut self, min_size: usize) -> Option<Arc<VecBuffer>> {
+        let reusable = self
+            .free
+            .iter()
+            .position(|buffer| Arc::strong_count(buffer) == 1 && buffer.len() >= min_size);
+        let buffer = match reusable {
+            Some(index) => {
+                let mut buffer = self.free.swap_remove(index);
+                Arc::get_mut(&mut buffer)
+                    .unwrap()
+                    .data
+                    .get_mut()
+                    .fill(POISON);
+                buffer
+            }
+            None => {
+                self.live.fetch_add(1, Ordering::SeqCst);
+                Arc::new(VecBuffer {
+                    data: UnsafeCell::new(vec![0; min_size]),
+                    live: Arc::clone(&self.live),
+                })
+            }
+        };
+        if self.share {
+            self.free.push(Arc::clone(&buffer));
+        }
+        Some(buffer)
+    }
+
+    fn release(&mut self, buffer: Arc<VecBuffer>) {
+        self.free.push(buffer);
+    }
+}
+
+fn assert_plane_gray(plane: PlaneRef, width: u32, height: u32) {
+    assert_eq!((plane.width(), plane.height()), (width, height));
+    assert!(plane
+        .rows()
+        .all(|row| row.iter().all(|&sample| sample == GRAY)));
+}
+
+fn assert_gray(frame: &SharedFrame, width: u32, height: u32) {
+    assert_eq!((frame.width(), frame.height()), (width, height));
+    assert_plane_gray(frame.y_plane(), width, height);
+    let (uv_width, uv_height) = (width.div_ceil(2), height.div_ceil(2));
+    assert_plane_gray(frame.u_plane(), uv_width, uv_height);
+    assert_plane_gray(frame.v_plane(), uv_width, uv_height);
+}
+
+#[test]
+fn shared_frames_survive_later_decodes() {
+    let sizes = [(64, 64), (96, 40), (33, 17), (64, 64), (128, 72)];
+    let pool = VecPool::default();
+    let live = Arc::clone(&pool.live);
+
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.set_frame_buffer_pool(pool).unwrap();
+
+    let mut kept = Vec::new();
+    for (pts, &(width, height)) in sizes.iter().enumerate() {
+        decoder
+            .decode(&KeyFrame::new(width, height).encode(), pts as u64)
+            .unwrap();
+        let frame = decoder.next_frame_ref().unwrap().unwrap();
+        let shared = frame.to_shared().unwrap();
+        assert_eq!(shared.pts(), pts as u64);
+        kept.push((shared, width, height));
+    }
+    // Cycle libvpx's buffers through the pool, reusing and poisoning
+    // every buffer no frame holds on to.
+    for pts in 0..20 {
+        decoder
+            .decode(&KeyFrame::new(48, 48).encode(), 100 + pts)
+            .unwrap();
+        while decoder.next_frame_ref().unwrap().is_some() {}
+    }
+
+    for (frame, width, height) in &kept {
+        assert_gray(frame, *width, *height);
+    }
+
+    drop(decoder);
+    assert_eq!(live.load(Ordering::SeqCst), sizes.len());
+    drop(kept);
+    // Every reference libvpx and the frames took has been given back.
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn shared_buffers_are_rejected() {
+    let pool = VecPool {
+        share: true,
+        ..VecPool::default()
+    };
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.set_frame_buffer_pool(pool).unwrap();
+    assert!(decoder.decode(&KeyFrame::new(64, 64).encode(), 0).is_err());
+}
+
+#[test]
+fn to_shared_needs_a_pool() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.decode(&KeyFrame::new(64, 64).encode(), 0).unwrap();
+    let frame = decoder.next_frame_ref().unwrap().unwrap();
+    assert!(frame.to_shared().is_none());
+}
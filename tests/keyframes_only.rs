@@ -0,0 +1,40 @@
+mod common;
+
+use common::{show_existing_frame, KeyFrame};
+use vp9::Vp9Decoder;
+
+fn frames(decoder: &mut Vp9Decoder) -> Vec<u64> {
+    let mut pts = Vec::new();
+    while let Some(frame) = decoder.next_frame_ref().unwrap() {
+        pts.push(frame.pts());
+    }
+    pts
+}
+
+#[test]
+fn inter_frames_are_dropped() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.set_keyframes_only(true);
+    decoder.decode(&show_existing_frame(0), 0).unwrap();
+    assert!(frames(&mut decoder).is_empty());
+    decoder.decode(&KeyFrame::new(64, 64).encode(), 1).unwrap();
+    assert_eq!(frames(&mut decoder), [1]);
+    decoder.decode(&show_existing_frame(0), 2).unwrap();
+    assert!(frames(&mut decoder).is_empty());
+}
+
+#[test]
+fn turning_it_off_waits_for_a_keyframe() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.decode(&KeyFrame::new(64, 64).encode(), 0).unwrap();
+    assert_eq!(frames(&mut decoder), [0]);
+
+    decoder.set_keyframes_only(true);
+    decoder.set_keyframes_only(false);
+    decoder.decode(&show_existing_frame(0), 1).unwrap();
+    assert!(frames(&mut decoder).is_empty());
+
+    decoder.decode(&KeyFrame::new(64, 64).encode(), 2).unwrap();
+    decoder.decode(&show_existing_frame(0), 3).unwrap();
+    assert_eq!(frames(&mut decoder), [2, 3]);
+}
@@ -0,0 +1,31 @@
+use vp9::{Error, PostProcConfig, Vp9Decoder};
+
+const DEBLOCK: PostProcConfig = PostProcConfig {
+    deblock: Some(4),
+    demacroblock: false,
+    noise: None,
+    mfqe: false,
+};
+
+#[test]
+fn set_postproc_needs_postproc_enabled() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    assert!(matches!(
+        decoder.set_postproc(DEBLOCK),
+        Err(Error::Incapable(_))
+    ));
+}
+
+#[test]
+fn set_postproc_changes_filters() {
+    let mut decoder = match Vp9Decoder::builder()
+        .postproc(PostProcConfig::default())
+        .build()
+    {
+        Ok(decoder) => decoder,
+        // libvpx built without `--enable-postproc`.
+        Err(Error::MissingCapability(_)) => return,
+        Err(e) => panic!("{e}"),
+    };
+    decoder.set_postproc(DEBLOCK).unwrap();
+}
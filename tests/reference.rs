@@ -0,0 +1,64 @@
+mod common;
+
+use common::{KeyFrame, GRAY};
+use vp9::{Frame, RefFrame, Vp9Decoder};
+
+#[test]
+fn copies_last_reference() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    // Not a multiple of 8, so libvpx's buffer is larger than the frame.
+    decoder.decode(&KeyFrame::new(33, 17).encode(), 0).unwrap();
+
+    let mut frame: Frame = Frame::default();
+    decoder.copy_reference(RefFrame::Last, &mut frame).unwrap();
+    assert_eq!((frame.width(), frame.height()), (33, 17));
+    assert_eq!((frame.uv_width(), frame.uv_height()), (17, 9));
+    assert!(frame.y_plane().iter().all(|&y| y == GRAY));
+    assert!(frame.v_plane().iter().all(|&v| v == GRAY));
+
+    let slot = decoder.reference_frame(0).unwrap();
+    assert_eq!(frame.y_plane(), slot.y_plane());
+    assert_eq!(frame.u_plane(), slot.u_plane());
+}
+
+#[test]
+fn copy_reference_follows_resolution_changes() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    for &(width, height) in &[(64, 64), (200, 130)] {
+        decoder
+            .decode(&KeyFrame::new(width, height).encode(), 0)
+            .unwrap();
+        let frame = decoder.reference(RefFrame::Last).unwrap();
+        assert_eq!((frame.width(), frame.height()), (width, height));
+    }
+}
+
+#[test]
+fn copy_reference_fails_before_first_frame() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    assert!(decoder.reference(RefFrame::Last).is_err());
+}
+
+#[test]
+fn copy_reference_rejects_wrong_sample_type() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.decode(&KeyFrame::new(64, 64).encode(), 0).unwrap();
+    let mut frame: Frame<u16> = Frame::default();
+    assert!(decoder.copy_reference(RefFrame::Last, &mut frame).is_err());
+}
+
+#[test]
+fn set_reference_round_trips_odd_sizes() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.decode(&KeyFrame::new(33, 17).encode(), 0).unwrap();
+
+    // All zero, unlike the gray the stream decodes to.
+    let injected = Frame::new(33, 17);
+    decoder.set_reference(RefFrame::Last, &injected).unwrap();
+
+    let frame = decoder.reference(RefFrame::Last).unwrap();
+    assert_eq!((frame.width(), frame.height()), (33, 17));
+    assert_eq!(frame.y_plane(), injected.y_plane());
+    assert_eq!(frame.u_plane(), injected.u_plane());
+    assert_eq!(frame.v_plane(), injected.v_plane());
+}
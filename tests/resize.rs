@@ -0,0 +1,64 @@
+mod common;
+
+use common::{KeyFrame, GRAY};
+use vp9::{Frame, Vp9Decoder};
+
+#[test]
+fn frame_follows_resolution_changes() {
+    let sizes = [(64, 64), (96, 40), (33, 17), (200, 130), (64, 64)];
+
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let mut frame: Frame = Frame::default();
+    for (pts, &(width, height)) in sizes.iter().enumerate() {
+        decoder
+            .decode(&KeyFrame::new(width, height).encode(), pts as u64)
+            .unwrap();
+        assert!(decoder.next_frame(&mut frame).unwrap());
+        assert!(!decoder.next_frame(&mut frame).unwrap());
+
+        assert_eq!((frame.width(), frame.height()), (width, height));
+        assert_eq!(
+            (frame.uv_width(), frame.uv_height()),
+            (width.div_ceil(2), height.div_ceil(2))
+        );
+        assert_eq!(frame.y_plane().len(), (width * height) as usize);
+        assert!(frame.y_plane().iter().all(|&y| y == GRAY));
+        assert!(frame.u_plane().iter().all(|&u| u == GRAY));
+        assert_eq!(decoder.frame_size().unwrap(), (width, height));
+    }
+}
+
+#[test]
+fn reused_frame_shrinks_and_grows() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let mut frame = Frame::new(320, 240);
+    for &(width, height) in &[(16, 16), (320, 240)] {
+        decoder
+            .decode(&KeyFrame::new(width, height).encode(), 0)
+            .unwrap();
+        assert!(decoder.next_frame(&mut frame).unwrap());
+        assert_eq!((frame.width(), frame.height()), (width, height));
+        assert_eq!(
+            frame.v_plane().len(),
+            (frame.uv_width() * frame.uv_height()) as usize
+        );
+    }
+}
+
+#[test]
+fn frame_carries_its_display_size() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let mut frame: Frame = Frame::default();
+    let keyframes = [
+        KeyFrame::new(64, 48).render_size(128, 48),
+        KeyFrame::new(64, 48),
+        KeyFrame::new(40, 40).render_size(20, 30),
+    ];
+    for (pts, keyframe) in keyframes.iter().enumerate() {
+        decoder.decode(&keyframe.encode(), pts as u64).unwrap();
+        assert!(decoder.next_frame(&mut frame).unwrap());
+        assert_eq!(frame.display_size(), decoder.display_size().unwrap());
+    }
+    assert_eq!((frame.width(), frame.height()), (40, 40));
+    assert_eq!(frame.display_size(), (20, 30));
+}
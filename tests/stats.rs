@@ -0,0 +1,47 @@
+mod common;
+
+use common::{superframe, KeyFrame};
+use vp9::{Frame, Vp9Decoder};
+
+#[test]
+fn frames_report_the_stats_of_their_packet() {
+    let quantizers = [40, 90, 200];
+
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let mut frame: Frame = Frame::default();
+    for (pts, &quantizer) in quantizers.iter().enumerate() {
+        let packet = KeyFrame::new(64, 64).base_q_idx(quantizer).encode();
+        decoder.decode(&packet, pts as u64).unwrap();
+        assert!(decoder.next_frame(&mut frame).unwrap());
+
+        assert_eq!(frame.pts(), pts as u64);
+        assert_eq!(frame.stats().quantizer, u32::from(quantizer));
+        // Keyframes refresh every reference slot.
+        assert_eq!(frame.stats().ref_updates, 0xff);
+    }
+
+    let stats = decoder.stream_stats();
+    assert_eq!(stats.frames(), 3);
+    assert_eq!(stats.min_quantizer(), Some(40));
+    assert_eq!(stats.max_quantizer(), Some(200));
+    assert_eq!(stats.refresh_counts(), [3; 8]);
+    assert_eq!(stats.non_reference_frames(), 0);
+}
+
+#[test]
+fn superframe_is_counted_once_with_its_last_frame() {
+    let packet = superframe(&[
+        KeyFrame::new(64, 64).base_q_idx(30).hidden().encode(),
+        KeyFrame::new(64, 64).base_q_idx(150).encode(),
+    ]);
+
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let mut frame: Frame = Frame::default();
+    decoder.decode(&packet, 0).unwrap();
+    assert!(decoder.next_frame(&mut frame).unwrap());
+    assert_eq!(frame.stats().quantizer, 150);
+
+    let stats = decoder.stream_stats();
+    assert_eq!(stats.frames(), 1);
+    assert_eq!(stats.min_quantizer(), Some(150));
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::{show_existing_frame, KeyFrame};
+use vp9::{peek_stream_info, StreamInfo, Vp9Decoder};
+
+#[test]
+fn peeks_keyframes() {
+    let info = peek_stream_info(&KeyFrame::new(33, 17).encode()).unwrap();
+    assert_eq!(
+        info,
+        StreamInfo {
+            width: 33,
+            height: 17,
+            is_keyframe: true,
+        }
+    );
+
+    let info = peek_stream_info(&KeyFrame::new(96, 40).hidden().encode()).unwrap();
+    assert_eq!((info.width, info.height), (96, 40));
+    assert!(info.is_keyframe);
+}
+
+#[test]
+fn peeks_show_existing_frame() {
+    // The header carries nothing but the slot to show.
+    let info = peek_stream_info(&show_existing_frame(0)).unwrap();
+    assert_eq!(info, StreamInfo::default());
+}
+
+#[test]
+fn peek_rejects_empty_and_garbage_input() {
+    assert!(peek_stream_info(&[]).is_err());
+    // A frame marker of 0 instead of 2.
+    assert!(peek_stream_info(&[0; 16]).is_err());
+    // A keyframe header cut short.
+    assert!(peek_stream_info(&KeyFrame::new(64, 64).encode()[..4]).is_err());
+}
+
+#[test]
+fn decoder_keeps_the_first_keyframe_info() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.decode(&KeyFrame::new(33, 17).encode(), 0).unwrap();
+    let first = decoder.stream_info().unwrap();
+    assert_eq!(
+        first,
+        StreamInfo {
+            width: 33,
+            height: 17,
+            is_keyframe: true,
+        }
+    );
+
+    decoder.decode(&show_existing_frame(0), 1).unwrap();
+    decoder.decode(&KeyFrame::new(96, 40).encode(), 2).unwrap();
+    assert_eq!(decoder.stream_info().unwrap(), first);
+}
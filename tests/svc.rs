@@ -0,0 +1,61 @@
+mod common;
+
+use common::{superframe, KeyFrame, GRAY};
+use vp9::{Frame, Vp9Decoder};
+
+/// Layer sizes of a three-layer spatial SVC stream, lowest first.
+const LAYERS: [(u32, u32); 3] = [(32, 18), (64, 36), (128, 72)];
+
+/// A superframe with one frame per spatial layer. Like libvpx's SVC
+/// encoder, every layer is shown; the decoder outputs the last one.
+fn svc_superframe() -> Vec<u8> {
+    let frames: Vec<Vec<u8>> = LAYERS
+        .iter()
+        .map(|&(width, height)| KeyFrame::new(width, height).encode())
+        .collect();
+    superframe(&frames)
+}
+
+fn decode(decoder: &mut Vp9Decoder) -> Frame {
+    let mut frame = Frame::default();
+    decoder.decode(&svc_superframe(), 0).unwrap();
+    assert!(decoder.next_frame(&mut frame).unwrap());
+    assert!(!decoder.next_frame(&mut frame).unwrap());
+    assert!(frame.y_plane().iter().all(|&y| y == GRAY));
+    frame
+}
+
+#[test]
+fn decodes_all_layers_by_default() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    let frame = decode(&mut decoder);
+    assert_eq!((frame.width(), frame.height()), LAYERS[2]);
+}
+
+#[test]
+fn max_spatial_layer_limits_output_size() {
+    for (layer, &size) in LAYERS.iter().enumerate() {
+        let mut decoder = Vp9Decoder::new().unwrap();
+        decoder.set_max_spatial_layer(layer as u32).unwrap();
+        let frame = decode(&mut decoder);
+        assert_eq!((frame.width(), frame.height()), size);
+        assert_eq!(decoder.frame_size().unwrap(), size);
+    }
+}
+
+#[test]
+fn max_spatial_layer_above_top_layer_decodes_everything() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.set_max_spatial_layer(7).unwrap();
+    let frame = decode(&mut decoder);
+    assert_eq!((frame.width(), frame.height()), LAYERS[2]);
+}
+
+#[test]
+fn max_spatial_layer_can_change_mid_stream() {
+    let mut decoder = Vp9Decoder::new().unwrap();
+    decoder.set_max_spatial_layer(0).unwrap();
+    assert_eq!(decode(&mut decoder).width(), LAYERS[0].0);
+    decoder.set_max_spatial_layer(2).unwrap();
+    assert_eq!(decode(&mut decoder).width(), LAYERS[2].0);
+}
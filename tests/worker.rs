@@ -0,0 +1,56 @@
+mod common;
+
+use common::{show_existing_frame, KeyFrame, GRAY};
+use vp9::{DecoderWorker, Frame, Vp9Decoder, WorkerOutput};
+
+/// Receives everything one packet produced.
+fn recv_packet(worker: &DecoderWorker) -> (Vec<Frame>, usize) {
+    let mut received = Vec::new();
+    loop {
+        match worker.recv().expect("worker stopped").unwrap() {
+            WorkerOutput::Frame(frame) => received.push(frame),
+            WorkerOutput::Decoded { frames } => return (received, frames),
+        }
+    }
+}
+
+#[test]
+fn marks_packets_without_frames() {
+    let worker = DecoderWorker::new(Vp9Decoder::new().unwrap());
+
+    // A hidden frame produces no output, which must not block `recv`.
+    worker
+        .send(KeyFrame::new(64, 48).hidden().encode(), 0)
+        .unwrap();
+    let (received, frames) = recv_packet(&worker);
+    assert!(received.is_empty());
+    assert_eq!(frames, 0);
+
+    worker.send(show_existing_frame(0), 1).unwrap();
+    let (received, frames) = recv_packet(&worker);
+    assert_eq!(frames, 1);
+    assert_eq!(received.len(), 1);
+    assert_eq!((received[0].width(), received[0].height()), (64, 48));
+    assert_eq!(received[0].pts(), 1);
+    assert!(received[0].y_plane().iter().all(|&y| y == GRAY));
+
+    worker.flush().unwrap();
+    let (received, frames) = recv_packet(&worker);
+    assert!(received.is_empty());
+    assert_eq!(frames, 0);
+}
+
+#[test]
+fn marks_packet_after_error() {
+    let worker: DecoderWorker = DecoderWorker::new(Vp9Decoder::new().unwrap());
+    worker.send(vec![0xff; 16], 0).unwrap();
+    assert!(worker.recv().unwrap().is_err());
+    assert!(matches!(
+        worker.recv().unwrap(),
+        Ok(WorkerOutput::Decoded { frames: 0 })
+    ));
+
+    worker.send(KeyFrame::new(32, 32).encode(), 1).unwrap();
+    let (received, frames) = recv_packet(&worker);
+    assert_eq!((received.len(), frames), (1, 1));
+}
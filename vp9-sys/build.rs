@@ -0,0 +1,380 @@
+//! This build script links libvpx into the crate.
+//!
+//! If the `system` feature is enabled (or `VPX_SYSTEM=1` / `VPX_LIB_DIR`
+//! is set), an installed libvpx is used. Its headers are checked against
+//! the ABI version of the checked-in bindings.
+//!
+//! Otherwise, if the `libvpx` submodule is checked out (or the `vendored`
+//! feature is enabled), libvpx is compiled from source with its own
+//! `configure` script, without network access. As a last resort, a precompiled
+//! static library is downloaded from https://github.com/caelunshun/libvpx-binaries.
+//!
+//! With the `bindgen` feature, the FFI bindings are regenerated from the
+//! headers of whichever libvpx was selected.
+
+use std::{
+    env, fs,
+    io::Read,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde::Deserialize;
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "macos")] {
+        const OS_SPECIFIER: &str = "macos";
+    } else if #[cfg(unix)] {
+        const OS_SPECIFIER: &str = "linux";
+    } else if #[cfg(windows)] {
+        const OS_SPECIFIER: &str = "windows";
+    }
+}
+
+/// See https://docs.github.com/en/rest/reference/repos#get-the-latest-release
+///
+/// Most fields are omitted.
+#[derive(Debug, Deserialize)]
+struct Release {
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    id: i32,
+    name: String,
+}
+
+fn get_latest_release() -> Result<Release, Box<dyn std::error::Error>> {
+    Ok(
+        ureq::get("https://api.github.com/repos/caelunshun/libvpx-binaries/releases/latest")
+            .set("Accept", "application/vnd.github.v3+json")
+            .call()?
+            .into_json()?,
+    )
+}
+
+fn download_asset(asset: &Asset) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut data = Vec::new();
+    ureq::get(&format!(
+        "https://api.github.com/repos/caelunshun/libvpx-binaries/releases/assets/{}",
+        asset.id
+    ))
+    .set("Accept", "application/octet-stream")
+    .call()?
+    .into_reader()
+    .read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Downloads the precompiled libvpx into `out_dir`.
+fn download_prebuilt(out_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let release = get_latest_release()?;
+    let asset = release
+        .assets
+        .iter()
+        .find(|a| a.name == format!("libvpx-{}.a", OS_SPECIFIER))
+        .unwrap_or_else(|| {
+            panic!(
+                "no precompiled binary available for {} - {:#?}",
+                OS_SPECIFIER, release.assets
+            )
+        });
+    let data = download_asset(asset)?;
+    fs::write(out_dir.join("libvpx.a"), &data)?;
+
+    println!("cargo:rustc-link-lib=static=vpx");
+    println!("cargo:rustc-link-search={}", out_dir.display());
+
+    Ok(())
+}
+
+/// Maps a Rust target triple to the `--target` understood by libvpx's `configure`.
+///
+/// Only needed when cross-compiling; for native builds `configure`
+/// detects the toolchain itself.
+fn libvpx_target(target: &str) -> Option<&'static str> {
+    Some(match target {
+        "x86_64-unknown-linux-gnu" | "x86_64-unknown-linux-musl" => "x86_64-linux-gcc",
+        "i686-unknown-linux-gnu" => "x86-linux-gcc",
+        "aarch64-unknown-linux-gnu" | "aarch64-unknown-linux-musl" => "arm64-linux-gcc",
+        "armv7-unknown-linux-gnueabihf" => "armv7-linux-gcc",
+        "x86_64-apple-darwin" => "x86_64-darwin20-gcc",
+        "aarch64-apple-darwin" => "arm64-darwin20-gcc",
+        _ => return None,
+    })
+}
+
+/// Compiles libvpx from the `libvpx` submodule into `out_dir`.
+fn build_vendored(source_dir: &Path, out_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let build_dir = out_dir.join("libvpx-build");
+    fs::create_dir_all(&build_dir)?;
+
+    let mut configure = Command::new(source_dir.join("configure"));
+    configure.current_dir(&build_dir).args([
+        "--disable-examples",
+        "--disable-tools",
+        "--disable-docs",
+        "--disable-unit-tests",
+        "--disable-install-docs",
+        "--disable-install-bins",
+        "--enable-pic",
+        // Profile 2 and 3 (10- and 12-bit) streams.
+        "--enable-vp9-highbitdepth",
+        "--enable-postproc",
+        "--enable-vp9-postproc",
+    ]);
+
+    let target = env::var("TARGET")?;
+    if target != env::var("HOST")? {
+        let vpx_target = libvpx_target(&target)
+            .unwrap_or_else(|| panic!("cannot cross-compile libvpx for {}", target));
+        configure.arg(format!("--target={}", vpx_target));
+    }
+
+    // Escape hatch for toolchain quirks, e.g. `--target=generic-gnu`
+    // on hosts without an assembler.
+    if let Ok(flags) = env::var("VPX_CONFIGURE_FLAGS") {
+        configure.args(flags.split_whitespace());
+    }
+
+    run(&mut configure)?;
+
+    let jobs = env::var("NUM_JOBS").unwrap_or_else(|_| "1".to_owned());
+    run(Command::new("make")
+        .current_dir(&build_dir)
+        .arg(format!("-j{}", jobs))
+        .arg("libvpx.a"))?;
+
+    println!("cargo:rustc-link-lib=static=vpx");
+    println!("cargo:rustc-link-search={}", build_dir.display());
+    println!("cargo:rerun-if-env-changed=VPX_CONFIGURE_FLAGS");
+
+    Ok(())
+}
+
+/// Links an installed libvpx, either from `VPX_LIB_DIR` or through pkg-config.
+///
+/// Linking is dynamic unless `VPX_STATIC=1` is set. Returns the include
+/// directories containing the libvpx headers.
+fn link_system() -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let statik = env::var("VPX_STATIC").is_ok_and(|v| v == "1");
+
+    let include_dirs = if let Some(lib_dir) = env::var_os("VPX_LIB_DIR") {
+        let lib_dir = PathBuf::from(lib_dir);
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        if statik {
+            println!("cargo:rustc-link-lib=static=vpx");
+            if cfg!(unix) {
+                println!("cargo:rustc-link-lib=pthread");
+                println!("cargo:rustc-link-lib=m");
+            }
+        } else {
+            println!("cargo:rustc-link-lib=dylib=vpx");
+        }
+
+        let include_dir = env::var_os("VPX_INCLUDE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| lib_dir.join("../include"));
+        vec![include_dir]
+    } else {
+        pkg_config::Config::new()
+            .statik(statik)
+            .probe("vpx")?
+            .include_paths
+    };
+
+    // Generated bindings match the headers by construction.
+    if !cfg!(feature = "bindgen") {
+        check_abi_version(&include_dirs)?;
+    }
+    Ok(include_dirs)
+}
+
+/// Verifies that the libvpx headers in `include_dirs` declare the same
+/// `VPX_DECODER_ABI_VERSION` as the checked-in bindings.
+fn check_abi_version(include_dirs: &[PathBuf]) -> Result<(), Box<dyn std::error::Error>> {
+    let expected = expected_abi_version()?;
+
+    let mut defines = Vec::new();
+    for header in ["vpx_image.h", "vpx_codec.h", "vpx_decoder.h"] {
+        let path = include_dirs
+            .iter()
+            .map(|dir| dir.join("vpx").join(header))
+            .find(|path| path.exists())
+            .ok_or_else(|| format!("vpx/{} not found in {:?}", header, include_dirs))?;
+        println!("cargo:rerun-if-changed={}", path.display());
+        defines.extend(parse_abi_defines(&fs::read_to_string(&path)?));
+    }
+
+    let found = eval_define(&defines, "VPX_DECODER_ABI_VERSION")?;
+    if found != expected {
+        return Err(format!(
+            "system libvpx has VPX_DECODER_ABI_VERSION {}, but the bindings were generated for {}",
+            found, expected
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Returns the checked-in bindings used for the target platform.
+///
+/// Must agree with the selection in `src/lib.rs`.
+fn checked_in_bindings() -> Result<&'static str, Box<dyn std::error::Error>> {
+    let os = env::var("CARGO_CFG_TARGET_OS")?;
+    let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH")?;
+    Ok(match (os.as_str(), pointer_width.as_str()) {
+        ("macos", _) => "src/bindings/macos.rs",
+        ("linux", "64") => "src/bindings/linux.rs",
+        _ => {
+            return Err(format!(
+                "no checked-in bindings for {}; enable the `bindgen` feature",
+                env::var("TARGET")?
+            )
+            .into())
+        }
+    })
+}
+
+/// Reads `VPX_DECODER_ABI_VERSION` from the checked-in bindings.
+fn expected_abi_version() -> Result<u32, Box<dyn std::error::Error>> {
+    const PREFIX: &str = "pub const VPX_DECODER_ABI_VERSION: u32 = ";
+
+    let path = checked_in_bindings()?;
+    let bindings = fs::read_to_string(path)?;
+    let version = bindings
+        .lines()
+        .find_map(|line| line.strip_prefix(PREFIX))
+        .and_then(|rest| rest.strip_suffix(';'))
+        .ok_or_else(|| format!("VPX_DECODER_ABI_VERSION missing from {}", path))?;
+    Ok(version.parse()?)
+}
+
+/// Extracts `#define VPX_*_ABI_VERSION <expr>` lines as `(name, expr)` pairs.
+fn parse_abi_defines(header: &str) -> Vec<(String, String)> {
+    // clang-format splits long defines across lines.
+    header
+        .replace("\\\n", " ")
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.trim().strip_prefix("#define ")?.splitn(2, ' ');
+            let name = parts.next()?;
+            if !name.ends_with("_ABI_VERSION") {
+                return None;
+            }
+            // Drop the trailing doxygen comment.
+            let expr = parts.next()?.split("/*").next()?;
+            Some((name.to_owned(), expr.trim().to_owned()))
+        })
+        .collect()
+}
+
+/// Evaluates a define of the form `(4 + VPX_IMAGE_ABI_VERSION)`.
+fn eval_define(
+    defines: &[(String, String)],
+    name: &str,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let (_, expr) = defines
+        .iter()
+        .find(|(n, _)| n == name)
+        .ok_or_else(|| format!("{} is not defined", name))?;
+
+    expr.trim_matches(|c| c == '(' || c == ')')
+        .split('+')
+        .map(str::trim)
+        .map(|term| match term.parse() {
+            Ok(value) => Ok(value),
+            Err(_) => eval_define(defines, term),
+        })
+        .sum()
+}
+
+/// Generates bindings for `vpx.h` into `$OUT_DIR/bindings.rs`.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(
+    include_dirs: &[PathBuf],
+    out_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if include_dirs.is_empty() {
+        return Err("the `bindgen` feature needs the libvpx headers; \
+                    check out the libvpx submodule or set VPX_INCLUDE_DIR"
+            .into());
+    }
+
+    let mut builder = bindgen::Builder::default()
+        .header("vpx.h")
+        .allowlist_function("vpx_.*")
+        .allowlist_type("(vpx|vp8|vp9)_.*")
+        .allowlist_var("(VPX|VP8|VP9|vpx|vp8|vp9)_.*")
+        // Keep `size_t` as in the checked-in bindings.
+        .size_t_is_usize(false)
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()));
+    for dir in include_dirs {
+        builder = builder.clang_arg(format!("-I{}", dir.display()));
+    }
+
+    builder
+        .generate()?
+        .write_to_file(out_dir.join("bindings.rs"))?;
+    Ok(())
+}
+
+fn run(command: &mut Command) -> Result<(), Box<dyn std::error::Error>> {
+    let status = command.status()?;
+    if !status.success() {
+        return Err(format!("{:?} failed with {}", command, status).into());
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    let source_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("libvpx");
+
+    // An uninitialized submodule is an empty directory.
+    let have_source = source_dir.join("configure").exists();
+
+    let use_system = cfg!(feature = "system")
+        || env::var("VPX_SYSTEM").is_ok_and(|v| v == "1")
+        || env::var_os("VPX_LIB_DIR").is_some();
+
+    let include_dirs = if use_system {
+        link_system()?
+    } else if have_source {
+        build_vendored(&source_dir, &out_dir)?;
+        vec![source_dir]
+    } else if cfg!(feature = "vendored") {
+        panic!(
+            "the `vendored` feature requires the libvpx sources; \
+             run `git submodule update --init` first"
+        );
+    } else {
+        download_prebuilt(&out_dir)?;
+        // The precompiled library ships without headers.
+        env::var_os("VPX_INCLUDE_DIR")
+            .map(PathBuf::from)
+            .into_iter()
+            .collect()
+    };
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&include_dirs, &out_dir)?;
+
+    // Exposed to dependents' build scripts as `DEP_VPX_INCLUDE`.
+    if !include_dirs.is_empty() {
+        println!(
+            "cargo:include={}",
+            env::join_paths(&include_dirs)?.to_string_lossy()
+        );
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/bindings");
+    for var in ["VPX_SYSTEM", "VPX_LIB_DIR", "VPX_INCLUDE_DIR", "VPX_STATIC"] {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+    println!("cargo:rerun-if-changed=libvpx/configure");
+
+    Ok(())
+}
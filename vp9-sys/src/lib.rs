@@ -0,0 +1,34 @@
+//! Raw FFI bindings to libvpx.
+//!
+//! This crate owns the link to libvpx (`links = "vpx"`), so every crate
+//! in a build shares a single copy of the library. See `build.rs` for how
+//! libvpx is located.
+//!
+//! With the `bindgen` feature, bindings are generated by the build script
+//! against the libvpx headers actually being linked. Otherwise the checked-in
+//! set for the target platform is used.
+
+#![allow(warnings)]
+
+#[cfg(feature = "bindgen")]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(all(not(feature = "bindgen"), target_os = "macos"))]
+include!("bindings/macos.rs");
+
+#[cfg(all(
+    not(feature = "bindgen"),
+    target_os = "linux",
+    target_pointer_width = "64"
+))]
+include!("bindings/linux.rs");
+
+#[cfg(not(any(
+    feature = "bindgen",
+    target_os = "macos",
+    all(target_os = "linux", target_pointer_width = "64")
+)))]
+compile_error!(
+    "vp9-sys has no checked-in bindings for this target; enable the `bindgen` feature \
+     to generate them from the libvpx headers"
+);
@@ -0,0 +1,2 @@
+#include <vpx/vpx_decoder.h>
+#include <vpx/vp8dx.h>
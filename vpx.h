@@ -1,2 +0,0 @@
-#include "libvpx/vpx/vpx_decoder.h"
-#include "libvpx/vpx/vp8dx.h"